@@ -139,6 +139,11 @@ async fn main() -> Result<(), Error> {
                     password: env!("LAVALINK_PASSWORD").to_string(),
                     user_id: ctx.cache.current_user().id.into(),
                     session_id: None,
+                    resume_timeout: None,
+                    reconnect_policy: Default::default(),
+                    http_config: Default::default(),
+                    extra_headers: Vec::new(),
+                    connector: None,
                 };
 
                 let client = LavalinkClient::new(
@@ -146,7 +151,7 @@ async fn main() -> Result<(), Error> {
                     vec![node_local],
                     NodeDistributionStrategy::round_robin(),
                 )
-                .await;
+                .await?;
 
                 Ok(Data { lavalink: client })
             })
@@ -211,7 +211,7 @@ pub async fn remove(
         return Ok(());
     };
 
-    player.get_queue().remove(index)?;
+    player.get_queue().remove(index).await?;
 
     ctx.say("Removed successfully").await?;
 
@@ -230,7 +230,7 @@ pub async fn clear(ctx: Context<'_>) -> Result<(), Error> {
         return Ok(());
     };
 
-    player.get_queue().clear()?;
+    player.get_queue().clear().await?;
 
     ctx.say("Queue cleared successfully").await?;
 
@@ -268,8 +268,8 @@ pub async fn swap(
     let track1 = queue.get_track(index1 - 1).await?.unwrap();
     let track2 = queue.get_track(index1 - 2).await?.unwrap();
 
-    queue.swap(index1 - 1, track2)?;
-    queue.swap(index2 - 1, track1)?;
+    queue.swap(index1 - 1, track2).await?;
+    queue.swap(index2 - 1, track1).await?;
 
     ctx.say("Swapped successfully").await?;
 
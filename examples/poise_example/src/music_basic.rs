@@ -159,7 +159,7 @@ pub async fn play(
     }
 
     let queue = player.get_queue();
-    queue.append(tracks.into())?;
+    queue.append(tracks.into()).await?;
 
     if let Ok(player_data) = player.get_player().await {
         if player_data.track.is_none() && queue.get_track(0).await.is_ok_and(|x| x.is_some()) {
@@ -206,7 +206,7 @@ async def remove(ctx: Context) -> None:
     else:
         await ctx.respond(f"Removed: `{track.info.author} - {track.info.title}`")
 
-    queue.remove(ctx.options.index - 1)
+    await queue.remove(ctx.options.index - 1)
 
 
 @plugin.command()
@@ -231,7 +231,7 @@ async def clear(ctx: Context) -> None:
         await ctx.respond("The queue is already empty")
         return None
 
-    queue.clear()
+    await queue.clear()
     await ctx.respond("The queue has been cleared")
 
 
@@ -285,8 +285,8 @@ async def swap(ctx: Context) -> None:
     assert track1
     assert track2
 
-    queue.swap(ctx.options.index1 - 1, track2)
-    queue.swap(ctx.options.index2 - 1, track1)
+    await queue.swap(ctx.options.index1 - 1, track2)
+    await queue.swap(ctx.options.index2 - 1, track1)
 
     if track1.track.info.uri:
         track1_text = f"[`{track1.track.info.author} - {track1.track.info.title}`](<{track1.track.info.uri}>)"
@@ -322,7 +322,7 @@ async def shuffle(ctx: Context) -> None:
 
     random.shuffle(queue)
 
-    queue_ref.replace(queue)
+    await queue_ref.replace(queue)
 
     await ctx.respond("Shuffled the queue")
 
@@ -165,7 +165,7 @@ async def play(ctx: Context) -> None:
 
         loaded_tracks.user_data = {"requester_id": int(ctx.author.id)}
 
-        player_ctx.queue(loaded_tracks)
+        await player_ctx.queue(loaded_tracks)
 
         if loaded_tracks.info.uri:
             await ctx.respond(
@@ -181,7 +181,7 @@ async def play(ctx: Context) -> None:
 
         loaded_tracks[0].user_data = {"requester_id": int(ctx.author.id)}
 
-        player_ctx.queue(loaded_tracks[0])
+        await player_ctx.queue(loaded_tracks[0])
 
         if loaded_tracks[0].info.uri:
             await ctx.respond(
@@ -200,7 +200,7 @@ async def play(ctx: Context) -> None:
 
             track.user_data = {"requester_id": int(ctx.author.id)}
 
-            player_ctx.queue(track)
+            await player_ctx.queue(track)
 
             if track.info.uri:
                 await ctx.respond(
@@ -217,7 +217,7 @@ async def play(ctx: Context) -> None:
                 i.user_data = {"requester_id": int(ctx.author.id)}
 
             queue = player_ctx.get_queue()
-            queue.append(tracks)
+            await queue.append(tracks)
 
             await ctx.respond(f"Added playlist to queue: `{loaded_tracks.info.name}`")
 
@@ -1,3 +1,4 @@
+import datetime
 import typing as t
 
 from lavalink_rs.model.player import ConnectionInfo, Player, Filters
@@ -12,7 +13,14 @@ from lavalink_rs.model.events import (
     TrackStuck,
     WebSocketClosed,
     Ready,
+    NodeStateUpdate,
+    NodeConnected,
+    NodeDisconnected,
+    NodeReconnecting,
+    NodeResumed,
+    PluginEvent,
 )
+from lavalink_rs.model.client import NodeState
 
 __CD = t.TypeVar("__CD")
 __PD = t.TypeVar("__PD")
@@ -32,7 +40,13 @@ class LavalinkClient:
         self, guild_id: t.Union[GuildId, int]
     ) -> t.Optional[PlayerContext]: ...
     def get_node_by_index(self, idx: int) -> t.Optional[Node]: ...
+    def get_node_by_id(self, id: int) -> t.Optional[Node]: ...
+    async def add_node(self, node: NodeBuilder) -> Node: ...
+    async def remove_node(self, id: int, drain: bool) -> Node: ...
     async def get_node_for_guild(self, guild_id: t.Union[GuildId, int]) -> Node: ...
+    async def move_player(
+        self, guild_id: t.Union[GuildId, int], target_node: Node
+    ) -> Player: ...
     async def create_player(
         self,
         guild_id: t.Union[GuildId, int],
@@ -52,6 +66,16 @@ class LavalinkClient:
     ) -> PlayerContext: ...
     async def delete_player(self, guild_id: t.Union[GuildId, int]) -> None: ...
     async def delete_all_player_contexts(self) -> None: ...
+    async def snapshot_all(self) -> t.List[PlayerSnapshot]: ...
+    async def restore_player_context(
+        self,
+        snapshot: PlayerSnapshot,
+        endpoint: str,
+        token: str,
+        session_id: str,
+        channel_id: ChannelId,
+        data: t.Optional[__PD] = None,
+    ) -> PlayerContext: ...
     async def update_player(
         self,
         guild_id: t.Union[GuildId, int],
@@ -61,6 +85,11 @@ class LavalinkClient:
     async def load_tracks(
         self, guild_id: t.Union[GuildId, int], identifier: str
     ) -> Track: ...
+    def set_failover(self, enabled: bool) -> None: ...
+    def failover_enabled(self) -> bool: ...
+    def set_load_cache(self, config: t.Optional[LoadCacheConfig] = None) -> None: ...
+    def load_cache_stats(self) -> t.Optional[LoadCacheStats]: ...
+    def clear_load_cache(self) -> None: ...
     async def decode_track(
         self, guild_id: t.Union[GuildId, int], track: str
     ) -> TrackData: ...
@@ -95,6 +124,27 @@ class PlayerContext:
     def close(self) -> None: ...
     def skip(self) -> None: ...
     def finish(self, should_continue: bool) -> None: ...
+    def set_loop_mode(self, loop_mode: LoopMode) -> None: ...
+    async def get_loop_mode(self) -> LoopMode: ...
+    async def previous(self) -> t.Optional[TrackInQueue]: ...
+    def set_history_size(self, history_size: int) -> None: ...
+    async def snapshot(self) -> PlayerSnapshot: ...
+    def set_queue_policy(self, queue_policy: QueuePolicy) -> None: ...
+    def set_crossfade(self, crossfade: t.Optional[Crossfade] = None) -> None: ...
+    async def fade_volume(
+        self,
+        volume: float,
+        duration: datetime.timedelta,
+        curve: FadeCurve = FadeCurve.Linear,
+    ) -> bool: ...
+    async def transition_filters(
+        self,
+        filters: Filters,
+        duration: datetime.timedelta,
+        curve: FadeCurve = FadeCurve.Linear,
+    ) -> bool: ...
+    def cancel_transition(self) -> None: ...
+    async def get_queue_policy(self) -> QueuePolicy: ...
     def update_player_data(self, player: Player) -> None: ...
     async def get_player(self) -> Player: ...
     async def update_player(
@@ -106,17 +156,29 @@ class PlayerContext:
     async def set_pause(self, pause: bool) -> Player: ...
     async def set_volume(self, volume: int) -> Player: ...
     async def set_filters(self, filters: Filters) -> Player: ...
+    async def merge_filters(self, filters: Filters) -> Player: ...
     async def set_position_ms(self, position: int) -> Player: ...
-    def queue(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
+    async def queue(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
     def get_queue(self) -> QueueRef: ...
 
 
 class NodeBuilder:
+    """
+    A builder for the node.
+
+    Custom connectors are only available from Rust, the default connector of the enabled TLS
+    feature is always used.
+    """
+
     hostname: str
     is_ssl: bool
     password: str
     user_id: UserId
     session_id: t.Optional[str]
+    resume_timeout: t.Optional[int]
+    reconnect_policy: ReconnectPolicy
+    http_config: HttpConfig
+    extra_headers: t.List[t.Tuple[str, str]]
 
     def __init__(
         self,
@@ -126,6 +188,43 @@ class NodeBuilder:
         user_id: t.Union[UserId, int],
         session_id: t.Optional[str] = None,
         events: t.Optional[EventHandler] = None,
+        resume_timeout: t.Optional[int] = None,
+        reconnect_policy: t.Optional[ReconnectPolicy] = None,
+        http_config: t.Optional[HttpConfig] = None,
+        extra_headers: t.Optional[t.List[t.Tuple[str, str]]] = None,
+    ) -> None: ...
+
+
+class ReconnectPolicy:
+    initial_delay: datetime.timedelta
+    max_delay: datetime.timedelta
+    multiplier: float
+    jitter: bool
+    max_attempts: t.Optional[int]
+
+    def __init__(
+        self,
+        initial_delay: t.Optional[datetime.timedelta] = None,
+        max_delay: t.Optional[datetime.timedelta] = None,
+        multiplier: t.Optional[float] = None,
+        jitter: t.Optional[bool] = None,
+        max_attempts: t.Optional[int] = None,
+    ) -> None: ...
+    def delay(self, attempt: int) -> datetime.timedelta: ...
+
+
+class HttpConfig:
+    timeout: t.Optional[datetime.timedelta]
+    max_retries: int
+    retry_delay: datetime.timedelta
+    max_concurrent_requests: t.Optional[int]
+
+    def __init__(
+        self,
+        timeout: t.Optional[datetime.timedelta] = None,
+        max_retries: t.Optional[int] = None,
+        retry_delay: t.Optional[datetime.timedelta] = None,
+        max_concurrent_requests: t.Optional[int] = None,
     ) -> None: ...
 
 
@@ -154,6 +253,24 @@ class EventHandler:
     async def ready(
         self, client: LavalinkClient, session_id: str, event: Ready
     ) -> None: ...
+    async def node_state_update(
+        self, client: LavalinkClient, session_id: str, event: NodeStateUpdate
+    ) -> None: ...
+    async def node_connected(
+        self, client: LavalinkClient, session_id: str, event: NodeConnected
+    ) -> None: ...
+    async def node_disconnected(
+        self, client: LavalinkClient, session_id: str, event: NodeDisconnected
+    ) -> None: ...
+    async def node_reconnecting(
+        self, client: LavalinkClient, session_id: str, event: NodeReconnecting
+    ) -> None: ...
+    async def node_resumed(
+        self, client: LavalinkClient, session_id: str, event: NodeResumed
+    ) -> None: ...
+    async def plugin_event(
+        self, client: LavalinkClient, session_id: str, event: PluginEvent
+    ) -> None: ...
 
 
 class NodeDistributionStrategy:
@@ -176,7 +293,9 @@ class NodeDistributionStrategy:
 
 
 class Node:
+    id: int
     http: Http
+    state: NodeState
 
 
 class Http:
@@ -219,14 +338,80 @@ class QueueRef:
     async def get_queue(self) -> t.List[TrackInQueue]: ...
     async def get_track(self, index: int) -> t.Optional[TrackInQueue]: ...
     async def get_count(self) -> int: ...
-    def push_to_back(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
-    def push_to_front(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
-    def insert(self, index: int, track: t.Union[TrackInQueue, TrackData]) -> None: ...
-    def remove(self, index: int) -> None: ...
-    def clear(self) -> None: ...
-    def replace(self, tracks: t.Sequence[t.Union[TrackInQueue, TrackData]]) -> None: ...
-    def append(self, tracks: t.Sequence[t.Union[TrackInQueue, TrackData]]) -> None: ...
-    def swap(self, index: int, track: t.Union[TrackInQueue, TrackData]) -> None: ...
+    async def get_history(self) -> t.List[TrackInQueue]: ...
+    async def push_to_back(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
+    async def push_to_front(self, track: t.Union[TrackInQueue, TrackData]) -> None: ...
+    async def insert(self, index: int, track: t.Union[TrackInQueue, TrackData]) -> None: ...
+    async def remove(self, index: int) -> TrackInQueue: ...
+    async def clear(self) -> None: ...
+    async def shuffle(self, seed: t.Optional[int] = None) -> t.List[TrackInQueue]: ...
+    async def move_track(self, from_: int, to: int) -> TrackInQueue: ...
+    async def swap_indices(self, a: int, b: int) -> t.List[TrackInQueue]: ...
+    async def remove_range(self, start: int, end: int) -> t.List[TrackInQueue]: ...
+    async def dedupe(self) -> t.List[TrackInQueue]: ...
+    async def skip(self, amount: int) -> t.List[TrackInQueue]: ...
+    async def replace(self, tracks: t.Sequence[t.Union[TrackInQueue, TrackData]]) -> None: ...
+    async def append(self, tracks: t.Sequence[t.Union[TrackInQueue, TrackData]]) -> None: ...
+    async def swap(self, index: int, track: t.Union[TrackInQueue, TrackData]) -> TrackInQueue: ...
+
+
+class LoadCacheConfig:
+    capacity: int
+    ttl: datetime.timedelta
+    search_ttl: datetime.timedelta
+
+    def __init__(self) -> None: ...
+
+
+class LoadCacheStats:
+    hits: int
+    misses: int
+    len: int
+
+
+class FadeCurve:
+    Linear: FadeCurve
+    EaseIn: FadeCurve
+    EaseOut: FadeCurve
+    EaseInOut: FadeCurve
+
+
+class Crossfade:
+    fade_out: datetime.timedelta
+    fade_in: datetime.timedelta
+
+    def __init__(
+        self, fade_out: datetime.timedelta, fade_in: datetime.timedelta
+    ) -> None: ...
+
+
+class QueuePolicy:
+    max_length: t.Optional[int]
+    max_duration: t.Optional[datetime.timedelta]
+    max_tracks_per_requester: t.Optional[int]
+    requester_key: str
+    reject_streams: bool
+    fair: bool
+
+    def __init__(self) -> None: ...
+
+
+class PlayerSnapshot:
+    player: Player
+    current_track: t.Optional[TrackInQueue]
+    queue: t.List[TrackInQueue]
+    history: t.List[TrackInQueue]
+    loop_mode: LoopMode
+
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(json: str) -> PlayerSnapshot: ...
+
+
+class LoopMode:
+    Off: LoopMode
+    Track: LoopMode
+    Queue: LoopMode
 
 
 class TrackInQueue:
@@ -17,3 +17,11 @@ class NodeDistributionStrategy:
     def custom(
         func: t.Callable[[LavalinkClient, t.Union[GuildId, int]], t.Awaitable[int]],
     ) -> NodeDistributionStrategy: ...
+
+
+class NodeState:
+    Connecting: NodeState
+    Connected: NodeState
+    Resuming: NodeState
+    Disconnected: NodeState
+    Failed: NodeState
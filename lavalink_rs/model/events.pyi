@@ -1,7 +1,8 @@
 import typing as t
 from lavalink_rs import GuildId
 from lavalink_rs.model.player import State
-from lavalink_rs.model.track import TrackData, TrackError
+from lavalink_rs.model.track import TrackData, TrackError, JSON
+from lavalink_rs.model.client import NodeState
 
 
 class Ready:
@@ -93,3 +94,35 @@ class WebSocketClosed:
     by_remote: bool
     code: int
     guild_id: GuildId
+
+
+class NodeStateUpdate:
+    node_id: int
+    old_state: NodeState
+    new_state: NodeState
+
+
+class NodeConnected:
+    node_id: int
+
+
+class NodeDisconnected:
+    node_id: int
+    code: t.Optional[int]
+    reason: str
+
+
+class NodeReconnecting:
+    node_id: int
+    attempt: int
+    delay_ms: int
+
+
+class NodeResumed:
+    node_id: int
+
+
+class PluginEvent:
+    event_type: str
+    guild_id: t.Optional[GuildId]
+    raw: JSON
@@ -92,6 +92,21 @@ class Filters:
     plugin_filters: t.Optional[JSON]
 
     def __new__(cls) -> Filters: ...
+    def merge(self, other: Filters) -> None: ...
+    def validate(self) -> None: ...
+
+
+class FilterPreset:
+    BassBoost: FilterPreset
+    Nightcore: FilterPreset
+    Vaporwave: FilterPreset
+    EightD: FilterPreset
+    Karaoke: FilterPreset
+    Soft: FilterPreset
+
+    @staticmethod
+    def all() -> t.List[FilterPreset]: ...
+    def filters(self) -> Filters: ...
 
 
 class ConnectionInfo:
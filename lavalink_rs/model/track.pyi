@@ -21,6 +21,9 @@ class TrackData:
     plugin_info: t.Optional[JSON]
     user_data: t.Optional[JSON]
 
+    @staticmethod
+    def from_encoded(encoded: str) -> TrackData: ...
+
 class TrackInfo:
     identifier: str
     source_name: str
@@ -34,6 +37,8 @@ class TrackInfo:
     uri: t.Optional[str]
     length: int
 
+    def encode(self) -> str: ...
+
 class PlaylistData:
     tracks: t.List[TrackData]
     info: PlaylistInfo
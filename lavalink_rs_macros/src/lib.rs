@@ -50,8 +50,6 @@ use quote::quote;
 /// this closure:
 ///
 /// ```rust,no_run
-/// # #![feature(async_closure)]
-/// #
 /// async move |x: i32| x * 2 + 4
 /// # ;
 /// ```
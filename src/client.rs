@@ -1,29 +1,43 @@
 use crate::error::{LavalinkError, LavalinkResult};
+use crate::model::client::NodeState;
 use crate::model::*;
 use crate::node;
 use crate::player_context::*;
 
 use std::collections::VecDeque;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
-use ::http::header::HeaderMap;
+use ::http::header::{HeaderMap, HeaderName};
 use arc_swap::{ArcSwap, ArcSwapOption};
 use dashmap::DashMap;
+use futures::stream::Stream;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 use tokio::sync::Mutex;
 
+mod load_cache;
+
+pub use load_cache::{LoadCache, LoadCacheConfig, LoadCacheStats};
+
+/// The amount of events kept for the event streams before the slowest ones start missing events.
+const EVENT_STREAM_CAPACITY: usize = 1024;
+
 #[derive(Clone)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 /// The main client, where everything gets done, from events to requests to management.
 pub struct LavalinkClient {
-    pub nodes: Vec<Arc<node::Node>>,
+    pub nodes: Arc<ArcSwap<Vec<Arc<node::Node>>>>,
     pub players: Arc<DashMap<GuildId, (ArcSwapOption<PlayerContext>, Arc<node::Node>)>>,
     pub events: Arc<events::Events>,
     tx: UnboundedSender<client::ClientMessage>,
     user_id: UserId,
     user_data: Arc<dyn std::any::Any + Send + Sync>,
     strategy: client::NodeDistributionStrategy,
+    failover: Arc<AtomicBool>,
+    next_node_id: Arc<AtomicUsize>,
+    load_cache: Arc<ArcSwapOption<LoadCache>>,
+    pub(crate) event_tx: broadcast::Sender<events::NodeEvent>,
 }
 
 impl LavalinkClient {
@@ -34,11 +48,17 @@ impl LavalinkClient {
     ///
     /// - `events`: The lavalink event handler.
     /// - `nodes`: List of nodes to connect to.
+    ///
+    /// # Errors
+    /// Returns `LavalinkError::NoNodesLeft` if no nodes are provided,
+    /// `LavalinkError::InvalidHeaderName` or `LavalinkError::InvalidHeaderValue` if the password,
+    /// session ID or `extra_headers` of a node can't be sent as headers, and
+    /// `LavalinkError::IoError` if the native root certificates can't be loaded.
     pub async fn new(
         events: events::Events,
         nodes: Vec<node::NodeBuilder>,
         strategy: client::NodeDistributionStrategy,
-    ) -> LavalinkClient {
+    ) -> LavalinkResult<LavalinkClient> {
         Self::new_with_data(events, nodes, strategy, Arc::new(())).await
     }
 
@@ -50,157 +70,271 @@ impl LavalinkClient {
     /// - `events`: The lavalink event handler.
     /// - `nodes`: List of nodes to connect to.
     /// - `user_data`: Set the data that will be accessible from anywhere with the client.
+    ///
+    /// # Errors
+    /// Returns `LavalinkError::NoNodesLeft` if no nodes are provided,
+    /// `LavalinkError::InvalidHeaderName` or `LavalinkError::InvalidHeaderValue` if the password,
+    /// session ID or `extra_headers` of a node can't be sent as headers, and
+    /// `LavalinkError::IoError` if the native root certificates can't be loaded.
     pub async fn new_with_data<Data: std::any::Any + Send + Sync>(
         events: events::Events,
         nodes: Vec<node::NodeBuilder>,
         strategy: client::NodeDistributionStrategy,
         user_data: Arc<Data>,
-    ) -> LavalinkClient {
+    ) -> LavalinkResult<LavalinkClient> {
         if nodes.is_empty() {
-            panic!("At least one node must be provided.");
+            return Err(LavalinkError::NoNodesLeft);
         }
 
-        let mut built_nodes = Vec::new();
-
-        for (idx, i) in nodes.into_iter().enumerate() {
-            let mut headers = HeaderMap::new();
-            headers.insert("Authorization", i.password.parse().unwrap());
-            headers.insert("User-Id", i.user_id.0.to_string().parse().unwrap());
-            headers.insert("Connection", "keep-alive".parse().unwrap());
-
-            if let Some(session_id) = &i.session_id {
-                headers.insert("Session-Id", session_id.parse().unwrap());
-            }
-
-            headers.insert(
-                "Client-Name",
-                format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
-                    .to_string()
-                    .parse()
-                    .unwrap(),
-            );
-
-            #[cfg(feature = "_rustls-webpki-roots")]
-            let https_connector = hyper_rustls::HttpsConnectorBuilder::new()
-                .with_webpki_roots()
-                .https_or_http()
-                .enable_all_versions()
-                .build();
-            #[cfg(feature = "_rustls-native-roots")]
-            let https_connector = hyper_rustls::HttpsConnectorBuilder::new()
-                .with_native_roots()
-                .expect("no native root CA certificates found")
-                .https_or_http()
-                .enable_all_versions()
-                .build();
-            #[cfg(feature = "_native-tls")]
-            let https_connector = hyper_tls::HttpsConnector::new();
-
-            let request_client =
-                hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
-                    .pool_idle_timeout(std::time::Duration::from_secs(60))
-                    .pool_timer(hyper_util::rt::TokioTimer::new())
-                    .build(https_connector);
-
-            let node = if i.is_ssl {
-                let http = crate::http::Http {
-                    authority: i.hostname.clone(),
-                    rest_address: format!("https://{}/v4", i.hostname),
-                    rest_address_versionless: format!("https://{}", i.hostname),
-                    headers,
-                    request_client: request_client.into(),
-                };
-
-                node::Node {
-                    id: idx,
-                    websocket_address: format!("wss://{}/v4/websocket", i.hostname),
-                    http,
-                    events: i.events,
-                    password: Secret(i.password.into()),
-                    user_id: i.user_id,
-                    is_running: AtomicBool::new(false),
-                    session_id: ArcSwap::new(if let Some(id) = i.session_id {
-                        id.into()
-                    } else {
-                        idx.to_string().into()
-                    }),
-                    cpu: ArcSwap::new(Default::default()),
-                    memory: ArcSwap::new(Default::default()),
-                }
-            } else {
-                let http = crate::http::Http {
-                    authority: i.hostname.clone(),
-                    rest_address: format!("http://{}/v4", i.hostname),
-                    rest_address_versionless: format!("http://{}", i.hostname),
-                    headers,
-                    request_client: request_client.into(),
-                };
-
-                node::Node {
-                    id: idx,
-                    websocket_address: format!("ws://{}/v4/websocket", i.hostname),
-                    http,
-                    events: i.events,
-                    password: Secret(i.password.into()),
-                    user_id: i.user_id,
-                    is_running: AtomicBool::new(false),
-                    session_id: ArcSwap::new(if let Some(id) = i.session_id {
-                        id.into()
-                    } else {
-                        idx.to_string().into()
-                    }),
-                    cpu: ArcSwap::new(Default::default()),
-                    memory: ArcSwap::new(Default::default()),
-                }
-            };
-
-            let node_arc = Arc::new(node);
-
-            built_nodes.push(node_arc);
-        }
+        let built_nodes = nodes
+            .into_iter()
+            .enumerate()
+            .map(|(id, i)| Self::build_node(id, i).map(Arc::new))
+            .collect::<LavalinkResult<Vec<_>>>()?;
 
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
 
         let client = LavalinkClient {
             user_id: built_nodes[0].user_id,
-            nodes: built_nodes,
+            next_node_id: Arc::new(AtomicUsize::new(built_nodes.len())),
+            nodes: Arc::new(ArcSwap::from_pointee(built_nodes)),
             players: Arc::new(DashMap::new()),
             events: Arc::new(events),
             tx,
             user_data,
             strategy,
+            failover: Arc::new(AtomicBool::new(false)),
+            load_cache: Arc::new(ArcSwapOption::empty()),
+            event_tx: broadcast::channel(EVENT_STREAM_CAPACITY).0,
         };
 
-        for node in &*client.nodes {
+        tokio::spawn(LavalinkClient::handle_connection_info(client.clone(), rx));
+
+        for node in &**client.nodes.load() {
             if let Err(why) = node.connect(client.clone()).await {
                 error!("Failed to connect to the lavalink websocket: {}", why);
+
+                let node = node.clone();
+                let lavalink_client = client.clone();
+                tokio::spawn(async move { node.reconnect(lavalink_client).await });
             }
         }
 
-        tokio::spawn(LavalinkClient::handle_connection_info(client.clone(), rx));
-
-        let lavalink_client = client.clone();
+        Ok(client)
+    }
 
-        tokio::spawn(async move {
+    /// Get a stream of every event received from every node.
+    ///
+    /// The events are yielded before the event handlers are called. The stream only yields the
+    /// events received after it was created. If it's not consumed fast enough, the oldest events
+    /// are skipped.
+    pub fn event_stream(&self) -> impl Stream<Item = events::NodeEvent> + Send + 'static {
+        futures::stream::unfold(self.event_tx.subscribe(), |mut rx| async move {
             loop {
-                tokio::time::sleep(std::time::Duration::from_secs(15)).await;
-
-                for node in &*lavalink_client.nodes {
-                    if !node.is_running.load(Ordering::SeqCst) {
-                        if let Err(why) = node.connect(lavalink_client.clone()).await {
-                            error!("Failed to connect to the lavalink websocket: {}", why);
-                        }
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!("Event stream lagged behind, skipped {} events.", skipped)
                     }
+                    Err(broadcast::error::RecvError::Closed) => return None,
                 }
             }
+        })
+    }
+
+    fn build_node(id: usize, i: node::NodeBuilder) -> LavalinkResult<node::Node> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", i.password.parse()?);
+        headers.insert("User-Id", i.user_id.0.to_string().parse()?);
+        headers.insert("Connection", "keep-alive".parse()?);
+
+        if let Some(session_id) = &i.session_id {
+            headers.insert("Session-Id", session_id.parse()?);
+        }
+
+        headers.insert(
+            "Client-Name",
+            format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+                .to_string()
+                .parse()?,
+        );
+
+        let mut extra_headers = HeaderMap::new();
+
+        for (name, value) in &i.extra_headers {
+            extra_headers.append(HeaderName::from_bytes(name.as_bytes())?, value.parse()?);
+        }
+
+        headers.extend(extra_headers.clone());
+
+        let connector = match &i.connector {
+            Some(connector) => connector.clone(),
+            None => Self::default_connector()?,
+        };
+
+        let request_client =
+            hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+                .pool_idle_timeout(std::time::Duration::from_secs(60))
+                .pool_timer(hyper_util::rt::TokioTimer::new())
+                .build(connector);
+
+        let http = crate::http::Http::new(
+            &i.hostname,
+            i.is_ssl,
+            headers,
+            request_client.into(),
+            i.http_config,
+        );
+
+        let websocket_address = if i.is_ssl {
+            format!("wss://{}/v4/websocket", i.hostname)
+        } else {
+            format!("ws://{}/v4/websocket", i.hostname)
+        };
+
+        Ok(node::Node {
+            id,
+            websocket_address,
+            http,
+            events: i.events,
+            password: Secret(i.password.into()),
+            user_id: i.user_id,
+            is_running: AtomicBool::new(false),
+            state: ArcSwap::new(Default::default()),
+            resume_timeout: i.resume_timeout,
+            reconnect_policy: i.reconnect_policy.clone(),
+            resumable: AtomicBool::new(i.session_id.is_some()),
+            session_id: ArcSwap::new(if let Some(session_id) = i.session_id {
+                session_id.into()
+            } else {
+                id.to_string().into()
+            }),
+            shutdown: tokio::sync::watch::channel(false).0,
+            cpu: ArcSwap::new(Default::default()),
+            memory: ArcSwap::new(Default::default()),
+            extra_headers,
+            connector: i.connector,
+        })
+    }
+
+    fn default_connector() -> LavalinkResult<crate::http::Connector> {
+        #[cfg(feature = "_rustls-webpki-roots")]
+        let https_connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_all_versions()
+            .build();
+        #[cfg(feature = "_rustls-native-roots")]
+        let https_connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_native_roots()?
+            .https_or_http()
+            .enable_all_versions()
+            .build();
+        #[cfg(feature = "_native-tls")]
+        let https_connector = hyper_tls::HttpsConnector::new();
+
+        Ok(crate::http::Connector::new(https_connector))
+    }
+
+    /// Add a new node to the client, and connect to it.
+    ///
+    /// # Errors
+    /// Returns `LavalinkError::InvalidHeaderName` or `LavalinkError::InvalidHeaderValue` if the
+    /// password, session ID or `extra_headers` of the node can't be sent as headers, and
+    /// `LavalinkError::IoError` if the native root certificates can't be loaded.
+    pub async fn add_node(&self, node: node::NodeBuilder) -> LavalinkResult<Arc<node::Node>> {
+        let id = self.next_node_id.fetch_add(1, Ordering::SeqCst);
+        let node = Arc::new(Self::build_node(id, node)?);
+
+        self.nodes.rcu(|nodes| {
+            let mut nodes = (**nodes).clone();
+            nodes.push(node.clone());
+            nodes
+        });
+
+        if let Err(why) = node.connect(self.clone()).await {
+            error!("Failed to connect to the lavalink websocket: {}", why);
+
+            let node = node.clone();
+            let lavalink_client = self.clone();
+            tokio::spawn(async move { node.reconnect(lavalink_client).await });
+        }
+
+        Ok(node)
+    }
+
+    /// Remove a node from the client, and close the connection to it.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The ID of the node to remove.
+    /// - `drain`: If the players bound to the node should be moved to the other nodes, instead of
+    ///   being deleted.
+    ///
+    /// # Errors
+    /// Returns `LavalinkError::NodeNotFound` if there's no node with the given ID, and
+    /// `LavalinkError::NoNodesLeft` if it's the only node of the client.
+    pub async fn remove_node(&self, id: usize, drain: bool) -> LavalinkResult<Arc<node::Node>> {
+        let mut removed = Err(LavalinkError::NodeNotFound);
+
+        // The checks run inside the update, so concurrent removals cannot leave the client
+        // without nodes.
+        self.nodes.rcu(|nodes| {
+            removed = match nodes.iter().find(|x| x.id == id) {
+                None => Err(LavalinkError::NodeNotFound),
+                Some(_) if nodes.len() == 1 => Err(LavalinkError::NoNodesLeft),
+                Some(node) => Ok(node.clone()),
+            };
+
+            if removed.is_err() {
+                return nodes.clone();
+            }
+
+            Arc::new(
+                nodes
+                    .iter()
+                    .filter(|x| x.id != id)
+                    .cloned()
+                    .collect::<Vec<_>>(),
+            )
         });
 
-        client
+        let node = removed?;
+
+        if drain {
+            self.failover_players(&node).await;
+        } else {
+            let guild_ids = self
+                .players
+                .iter()
+                .filter(|i| i.1.id == id)
+                .map(|i| *i.key())
+                .collect::<Vec<_>>();
+
+            for guild_id in guild_ids {
+                if let Err(why) = self.delete_player(guild_id).await {
+                    error!(
+                        "Error deleting the player for guild {} from removed node {}: {}",
+                        guild_id.0, id, why
+                    );
+                }
+            }
+        }
+
+        node.shutdown.send_replace(true);
+        node.set_state(self, NodeState::Disconnected).await;
+
+        Ok(node)
     }
 
-    // Get a node based on the vector index when insrted into the client initially.
+    /// Get a node based on its position in the list of nodes.
     pub fn get_node_by_index(&self, idx: usize) -> Option<Arc<node::Node>> {
-        self.nodes.get(idx).cloned()
+        self.nodes.load().get(idx).cloned()
+    }
+
+    /// Get a node based on its ID.
+    pub fn get_node_by_id(&self, id: usize) -> Option<Arc<node::Node>> {
+        self.nodes.load().iter().find(|x| x.id == id).cloned()
     }
 
     /// Get the node assigned to a guild.
@@ -214,23 +348,24 @@ impl LavalinkClient {
 
         debug!("First time selecting node for guild {:?}", guild_id);
 
+        self.select_node(guild_id).await
+    }
+
+    /// Select a node for a guild using the distribution strategy.
+    async fn select_node(&self, guild_id: GuildId) -> Arc<node::Node> {
         use client::NodeDistributionStrategy::*;
 
+        let nodes = self.nodes.load();
+
         match &self.strategy {
-            Sharded => self
-                .get_node_by_index(guild_id.0 as usize % self.nodes.len())
-                .unwrap(),
+            Sharded => nodes[guild_id.0 as usize % nodes.len()].clone(),
             RoundRobin(x) => {
-                let mut idx = x.fetch_add(1, Ordering::SeqCst);
-                if idx == self.nodes.len() {
-                    x.store(1, Ordering::SeqCst);
-                    idx = 0;
-                }
+                let idx = x.fetch_add(1, Ordering::SeqCst);
 
-                self.get_node_by_index(idx).unwrap()
+                nodes[idx % nodes.len()].clone()
             }
             MainFallback => {
-                for node in &*self.nodes {
+                for node in &**nodes {
                     if node.is_running.load(Ordering::SeqCst) {
                         return node.clone();
                     }
@@ -239,7 +374,7 @@ impl LavalinkClient {
                 warn!("No nodes are currently running, waiting 5 seconds and trying again...");
                 tokio::time::sleep(std::time::Duration::from_secs(5)).await;
 
-                for node in &*self.nodes {
+                for node in &**self.nodes.load() {
                     if node.is_running.load(Ordering::SeqCst) {
                         return node.clone();
                     }
@@ -247,16 +382,14 @@ impl LavalinkClient {
 
                 warn!("No nodes are currently running, returning first node.");
 
-                self.get_node_by_index(0).unwrap()
+                nodes[0].clone()
             }
-            LowestLoad => self
-                .nodes
+            LowestLoad => nodes
                 .iter()
                 .min_by_key(|x| x.cpu.load().system_load.abs() as u8)
                 .unwrap()
                 .clone(),
-            HighestFreeMemory => self
-                .nodes
+            HighestFreeMemory => nodes
                 .iter()
                 .min_by_key(|x| x.memory.load().free)
                 .unwrap()
@@ -322,6 +455,149 @@ impl LavalinkClient {
         }
     }
 
+    /// Move the player of a guild to another node.
+    ///
+    /// The player gets recreated on the target node with the last known track, position, volume,
+    /// filters and voice state, and deleted from the previous node if it's still running.
+    /// The player context and its queue are kept intact.
+    ///
+    /// # Errors
+    /// Returns `LavalinkError::NodeNotFound` if the guild has no player bound to a node.
+    pub async fn move_player(
+        &self,
+        guild_id: impl Into<GuildId>,
+        target_node: Arc<node::Node>,
+    ) -> LavalinkResult<player::Player> {
+        let guild_id = guild_id.into();
+        let previous_node = self
+            .players
+            .get(&guild_id)
+            .map(|x| x.1.clone())
+            .ok_or(LavalinkError::NodeNotFound)?;
+        let player_context = self.get_player_context(guild_id);
+
+        let player = if let Some(player_context) = &player_context {
+            player_context.get_player().await?
+        } else {
+            previous_node
+                .http
+                .get_player(guild_id, &previous_node.session_id.load())
+                .await?
+        };
+
+        let player = target_node
+            .http
+            .update_player(
+                guild_id,
+                &target_node.session_id.load(),
+                &player.into(),
+                false,
+            )
+            .await?;
+
+        if let Some(mut entry) = self.players.get_mut(&guild_id) {
+            entry.1 = target_node.clone();
+        }
+
+        if let Some(player_context) = player_context {
+            player_context.update_player_data(player.clone())?;
+        }
+
+        if previous_node.id != target_node.id && previous_node.is_running.load(Ordering::SeqCst) {
+            if let Err(why) = previous_node
+                .http
+                .delete_player(guild_id, &previous_node.session_id.load())
+                .await
+            {
+                error!(
+                    "Error deleting the player for guild {} from node {}: {}",
+                    guild_id.0, previous_node.id, why
+                );
+            }
+        }
+
+        Ok(player)
+    }
+
+    /// Move the players bound to a node to another node when the connection to it is lost.
+    ///
+    /// Disabled by default, the players stay bound to the node until it reconnects. Failover is
+    /// only attempted for nodes without a `resume_timeout`, or when they fail to reconnect.
+    pub fn set_failover(&self, enabled: bool) {
+        self.failover.store(enabled, Ordering::SeqCst);
+    }
+
+    /// Get whether players are moved to another node when the connection to their node is lost.
+    pub fn failover_enabled(&self) -> bool {
+        self.failover.load(Ordering::SeqCst)
+    }
+
+    /// Move every player bound to a node that stopped running to another node.
+    ///
+    /// The target node of each player is selected with the distribution strategy, and the players
+    /// are moved concurrently.
+    pub(crate) async fn failover_players(&self, failed_node: &node::Node) {
+        let guild_ids = self
+            .players
+            .iter()
+            .filter(|i| i.1.id == failed_node.id)
+            .map(|i| *i.key())
+            .collect::<Vec<_>>();
+
+        if guild_ids.is_empty() {
+            return;
+        }
+
+        let running_nodes = self
+            .nodes
+            .load()
+            .iter()
+            .filter(|x| x.id != failed_node.id && x.is_running.load(Ordering::SeqCst))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if running_nodes.is_empty() {
+            warn!(
+                "No nodes are currently running, the players of node {} were not moved.",
+                failed_node.id
+            );
+            return;
+        }
+
+        debug!(
+            "Moving {} players out of node {}",
+            guild_ids.len(),
+            failed_node.id
+        );
+
+        let mut targets = Vec::with_capacity(guild_ids.len());
+
+        for guild_id in guild_ids {
+            let mut target_node = self.select_node(guild_id).await;
+
+            // The strategy doesn't know about the failure, and may select the failed node again.
+            if target_node.id == failed_node.id || !target_node.is_running.load(Ordering::SeqCst) {
+                target_node = running_nodes[guild_id.0 as usize % running_nodes.len()].clone();
+            }
+
+            targets.push((guild_id, target_node));
+        }
+
+        futures::future::join_all(
+            targets
+                .into_iter()
+                .map(|(guild_id, target_node)| async move {
+                    if let Err(why) = self.move_player(guild_id, target_node).await {
+                        error!(
+                            "Error moving the player for guild {} out of node {}: {}",
+                            guild_id.0, failed_node.id, why
+                        );
+                    }
+                }),
+        )
+        .await;
+    }
+
     /// Get the player context for a guild, if it exists.
     pub fn get_player_context(&self, guild_id: impl Into<GuildId>) -> Option<PlayerContext> {
         let guild_id = guild_id.into();
@@ -400,7 +676,7 @@ impl LavalinkClient {
             }
         }
 
-        let player = node
+        let mut player = node
             .http
             .update_player(
                 guild_id,
@@ -413,23 +689,119 @@ impl LavalinkClient {
             )
             .await?;
 
+        // Lavalink never returns the channel ID, so keep it around in case the player needs to be
+        // recreated.
+        player.voice = connection_info;
+
+        Ok(self
+            .start_player_context(node, player, user_data, None)
+            .await)
+    }
+
+    /// Recreates a player context from a snapshot taken with `PlayerContext::snapshot()`.
+    ///
+    /// The queue, history and loop mode are restored, and the track that was playing is played
+    /// again at the saved position, with the saved pause state, volume and filters.
+    ///
+    /// If a player context already exists for the guild, it gets replaced.
+    pub async fn restore_player_context(
+        &self,
+        snapshot: PlayerSnapshot,
+        connection_info: impl Into<player::ConnectionInfo>,
+    ) -> LavalinkResult<PlayerContext> {
+        self.restore_player_context_with_data(snapshot, connection_info, Arc::new(()))
+            .await
+    }
+
+    /// Recreates a player context from a snapshot, with custom user data.
+    ///
+    /// See `restore_player_context()` for details.
+    pub async fn restore_player_context_with_data<Data: std::any::Any + Send + Sync>(
+        &self,
+        snapshot: PlayerSnapshot,
+        connection_info: impl Into<player::ConnectionInfo>,
+        user_data: Arc<Data>,
+    ) -> LavalinkResult<PlayerContext> {
+        let guild_id = snapshot.player.guild_id;
+        let mut connection_info = connection_info.into();
+        connection_info.fix();
+
+        let node = self.get_node_for_guild(guild_id).await;
+
+        let mut update_player = http::UpdatePlayer::from(snapshot.player.clone());
+        update_player.voice = Some(connection_info.clone());
+        update_player.end_time = snapshot
+            .current_track
+            .as_ref()
+            .and_then(|x| x.end_time)
+            .filter(|_| snapshot.player.track.is_some())
+            .map(|x| x.as_millis() as u64);
+
+        let mut player = node
+            .http
+            .update_player(guild_id, &node.session_id.load(), &update_player, false)
+            .await?;
+
+        player.voice = connection_info;
+
+        if let Some(player_context) = self.get_player_context(guild_id) {
+            player_context.close()?;
+        }
+
+        Ok(self
+            .start_player_context(node, player, user_data, Some(snapshot))
+            .await)
+    }
+
+    /// Start the task of a new player context, and store it in the client.
+    async fn start_player_context(
+        &self,
+        node: Arc<node::Node>,
+        player: player::Player,
+        user_data: Arc<dyn std::any::Any + Send + Sync>,
+        snapshot: Option<PlayerSnapshot>,
+    ) -> PlayerContext {
+        let guild_id = player.guild_id;
+        let is_playing = player.track.is_some();
+
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let (queue_event_tx, _) = broadcast::channel(QUEUE_EVENT_CAPACITY);
 
         let player_dummy = PlayerContext {
             guild_id,
             client: self.clone(),
             tx,
+            queue_event_tx,
             user_data,
         };
 
-        let player_context = PlayerContextInner {
+        let mut player_context = PlayerContextInner {
             guild_id,
             queue: VecDeque::new(),
             player_data: player,
             dummy: player_dummy.clone(),
-            last_should_continue: true,
+            current_track: None,
+            loop_mode: Default::default(),
+            queue_empty_handler: None,
+            history: VecDeque::new(),
+            history_size: DEFAULT_HISTORY_SIZE,
+            queue_policy: Default::default(),
+            crossfade: None,
+            fade_task: None,
+            fading_out: None,
+            fade_base: None,
+            transition_task: None,
+            prefetch_task: None,
+            undecodable: None,
         };
 
+        if let Some(snapshot) = snapshot {
+            player_context.queue = snapshot.queue.into();
+            player_context.current_track = snapshot.current_track.filter(|_| is_playing);
+            player_context.history = snapshot.history.into();
+            player_context.loop_mode = snapshot.loop_mode;
+        }
+
         player_context.start(rx).await;
 
         self.players.insert(
@@ -437,7 +809,7 @@ impl LavalinkClient {
             (ArcSwapOption::new(Some(player_dummy.clone().into())), node),
         );
 
-        Ok(player_dummy)
+        player_dummy
     }
 
     /// Deletes and closes a specific player context, if it exists.
@@ -475,6 +847,74 @@ impl LavalinkClient {
         Ok(())
     }
 
+    /// Capture the state of every player context, to restore them later with
+    /// `restore_player_context()`.
+    pub async fn snapshot_all(&self) -> LavalinkResult<Vec<PlayerSnapshot>> {
+        let player_contexts = self
+            .players
+            .iter()
+            .filter_map(|i| i.0.load().clone().map(|x| (*x).clone()))
+            .collect::<Vec<_>>();
+
+        let mut snapshots = Vec::with_capacity(player_contexts.len());
+
+        for player_context in player_contexts {
+            snapshots.push(player_context.snapshot().await?);
+        }
+
+        Ok(snapshots)
+    }
+
+    /// Restore the player contexts bound to a node after its websocket reconnected.
+    ///
+    /// If the session was resumed, the player data is synchronized with the one on the node.
+    /// Otherwise, the players are recreated with their last known track, position, volume, filters
+    /// and voice state.
+    pub(crate) async fn restore_players(
+        &self,
+        node: &node::Node,
+        resumed: bool,
+    ) -> LavalinkResult<()> {
+        let session_id = node.session_id.load_full();
+        let remote_players = node.http.get_players(&session_id).await?;
+
+        let player_contexts = self
+            .players
+            .iter()
+            .filter(|i| i.1.id == node.id)
+            .filter_map(|i| i.0.load().clone().map(|x| (*x).clone()))
+            .collect::<Vec<_>>();
+
+        for player_context in player_contexts {
+            let guild_id = player_context.guild_id;
+
+            if resumed {
+                if let Some(player) = remote_players.iter().find(|x| x.guild_id == guild_id) {
+                    player_context.update_player_data(player.clone())?;
+                    continue;
+                }
+            }
+
+            debug!("Recreating the player for guild {:?}", guild_id);
+
+            let update_player = player_context.get_player().await?.into();
+
+            match node
+                .http
+                .update_player(guild_id, &session_id, &update_player, false)
+                .await
+            {
+                Ok(player) => player_context.update_player_data(player)?,
+                Err(why) => error!(
+                    "Error recreating the player for guild {}: {}",
+                    guild_id.0, why
+                ),
+            }
+        }
+
+        Ok(())
+    }
+
     /// Request a raw player update.
     pub async fn update_player(
         &self,
@@ -505,21 +945,52 @@ impl LavalinkClient {
     ///  - Can be a url: "https://youtu.be/watch?v=DrM2lo6B04I"
     ///  - A unique identifier: "DrM2lo6B04I"
     ///  - A search: "
+    ///
+    /// If a `LoadCache` is set with `set_load_cache()`, cached results are returned without
+    /// requesting the node.
     pub async fn load_tracks(
         &self,
         guild_id: impl Into<GuildId>,
         identifier: &str,
     ) -> LavalinkResult<track::Track> {
+        let load_cache = self.load_cache.load_full();
+
+        if let Some(track) = load_cache
+            .as_ref()
+            .and_then(|load_cache| load_cache.get(identifier))
+        {
+            return Ok(track);
+        }
+
         let guild_id = guild_id.into();
         let node = self.get_node_for_guild(guild_id).await;
 
         let result = node.http.load_tracks(identifier).await?;
 
+        if let Some(load_cache) = load_cache {
+            load_cache.insert(identifier, &result);
+        }
+
         Ok(result)
     }
 
+    /// Cache the results of `load_tracks()`, or stop caching them with None.
+    ///
+    /// Setting a new configuration starts with an empty cache.
+    pub fn set_load_cache(&self, config: Option<LoadCacheConfig>) {
+        self.load_cache
+            .store(config.map(|config| Arc::new(LoadCache::new(config))));
+    }
+
+    /// Get the cache of `load_tracks()` results, if one was set.
+    pub fn load_cache(&self) -> Option<Arc<LoadCache>> {
+        self.load_cache.load_full()
+    }
+
     /// Decode a single track into its info.
     ///
+    /// See `TrackData::from_encoded()` to decode tracks without requesting a node.
+    ///
     /// # Parameters
     ///
     /// - `track`: base64 encoded track data.
@@ -805,7 +1276,7 @@ impl LavalinkClient {
 
                     let mut entry = data.entry(guild_id).or_insert((None, None, None, None));
                     let session_id = entry.value().2.clone();
-                    let channel_id = entry.value().3.clone();
+                    let channel_id = entry.value().3;
                     *entry.value_mut() = (Some(token), endpoint, session_id, channel_id);
 
                     {
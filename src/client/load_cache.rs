@@ -0,0 +1,204 @@
+use crate::model::track::{Track, TrackLoadType};
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// How the results of `LavalinkClient::load_tracks()` are cached.
+pub struct LoadCacheConfig {
+    /// The maximum amount of results in the cache, the least recently used ones are removed first.
+    pub capacity: usize,
+    /// How long track and playlist results are cached for.
+    ///
+    /// `Duration::MAX` caches them until they are evicted.
+    pub ttl: Duration,
+    /// How long search results are cached for.
+    ///
+    /// They are usually cached for less time than tracks, as new results can show up.
+    pub search_ttl: Duration,
+}
+
+impl Default for LoadCacheConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 1000,
+            ttl: Duration::from_secs(60 * 60),
+            search_ttl: Duration::from_secs(5 * 60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all))]
+/// Statistics about the usage of a `LoadCache`.
+pub struct LoadCacheStats {
+    /// The amount of loads that were answered from the cache.
+    pub hits: u64,
+    /// The amount of loads that had to request a node.
+    pub misses: u64,
+    /// The amount of results currently in the cache.
+    pub len: usize,
+}
+
+struct Entry {
+    track: Track,
+    /// None if the TTL is too long to be represented, and the entry never expires.
+    expires_at: Option<Instant>,
+    last_used: u64,
+}
+
+impl Entry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|x| x <= now)
+    }
+}
+
+#[derive(Default)]
+struct Entries {
+    by_identifier: HashMap<String, Entry>,
+    /// The identifiers, ordered from the least to the most recently used.
+    by_use: BTreeMap<u64, String>,
+    next_use: u64,
+}
+
+/// A cache of track loading results, keyed by the identifier that was loaded.
+///
+/// Only track, playlist and search results are cached, errors and empty results are always
+/// requested again.
+pub struct LoadCache {
+    config: LoadCacheConfig,
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl LoadCache {
+    /// Create an empty cache.
+    pub fn new(config: LoadCacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(Entries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the configuration of the cache.
+    pub fn config(&self) -> LoadCacheConfig {
+        self.config
+    }
+
+    /// Get the cached result of loading `identifier`, if it has not expired.
+    ///
+    /// Counts as a hit or a miss in the statistics.
+    pub fn get(&self, identifier: &str) -> Option<Track> {
+        let mut entries = self.entries.lock().unwrap();
+        let entries = &mut *entries;
+
+        let track = match entries.by_identifier.get_mut(identifier) {
+            Some(entry) if !entry.is_expired(Instant::now()) => {
+                entries.by_use.remove(&entry.last_used);
+                entry.last_used = entries.next_use;
+                entries
+                    .by_use
+                    .insert(entry.last_used, identifier.to_string());
+                entries.next_use += 1;
+
+                Some(entry.track.clone())
+            }
+            Some(_) => {
+                entries.remove(identifier);
+                None
+            }
+            None => None,
+        };
+
+        if track.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+        }
+
+        track
+    }
+
+    /// Cache the result of loading `identifier`, if the type of result is cacheable.
+    pub fn insert(&self, identifier: &str, track: &Track) {
+        let ttl = match track.load_type {
+            TrackLoadType::Track | TrackLoadType::Playlist => self.config.ttl,
+            TrackLoadType::Search => self.config.search_ttl,
+            TrackLoadType::Empty | TrackLoadType::Error => return,
+        };
+
+        if ttl.is_zero() || self.config.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        let entries = &mut *entries;
+        let now = Instant::now();
+
+        entries.remove(identifier);
+
+        if entries.by_identifier.len() >= self.config.capacity {
+            entries
+                .by_identifier
+                .retain(|_, entry| !entry.is_expired(now));
+            let by_identifier = &entries.by_identifier;
+            entries
+                .by_use
+                .retain(|_, identifier| by_identifier.contains_key(identifier));
+        }
+
+        while entries.by_identifier.len() >= self.config.capacity {
+            let Some((_, oldest)) = entries.by_use.pop_first() else {
+                break;
+            };
+
+            entries.by_identifier.remove(&oldest);
+        }
+
+        let last_used = entries.next_use;
+        entries.next_use += 1;
+
+        entries.by_use.insert(last_used, identifier.to_string());
+        entries.by_identifier.insert(
+            identifier.to_string(),
+            Entry {
+                track: track.clone(),
+                expires_at: now.checked_add(ttl),
+                last_used,
+            },
+        );
+    }
+
+    /// Remove the cached result of loading `identifier`.
+    pub fn invalidate(&self, identifier: &str) {
+        self.entries.lock().unwrap().remove(identifier);
+    }
+
+    /// Remove every cached result.
+    pub fn clear(&self) {
+        *self.entries.lock().unwrap() = Entries::default();
+    }
+
+    /// Get the hit and miss counters of the cache.
+    pub fn stats(&self) -> LoadCacheStats {
+        LoadCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            len: self.entries.lock().unwrap().by_identifier.len(),
+        }
+    }
+}
+
+impl Entries {
+    fn remove(&mut self, identifier: &str) {
+        if let Some(entry) = self.by_identifier.remove(identifier) {
+            self.by_use.remove(&entry.last_used);
+        }
+    }
+}
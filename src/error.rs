@@ -4,7 +4,7 @@ use std::error::Error;
 use std::fmt::{Display, Formatter, Result};
 use std::io::Error as IoError;
 
-use ::http::header::InvalidHeaderValue;
+use ::http::header::{InvalidHeaderName, InvalidHeaderValue};
 use ::http::method::InvalidMethod;
 use ::http::uri::InvalidUri;
 use ::http::Error as HttpError;
@@ -18,7 +18,7 @@ use tokio_tungstenite::tungstenite::error::Error as TungsteniteError;
 use tokio_websockets::error::Error as WebsocketsError;
 
 #[cfg(feature = "python")]
-use pyo3::exceptions::PyException;
+use pyo3::exceptions::{PyException, PyIndexError, PyValueError};
 #[cfg(feature = "python")]
 use pyo3::PyErr;
 
@@ -43,6 +43,7 @@ pub(crate) enum RequestResult<T> {
 }
 
 impl<T> RequestResult<T> {
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_result(self) -> std::result::Result<T, ResponseError> {
         match self {
             Self::Ok(x) => Ok(x),
@@ -60,6 +61,7 @@ pub enum LavalinkError {
     WebsocketError(TungsteniteError),
     #[cfg(feature = "_websockets")]
     WebsocketError(WebsocketsError),
+    InvalidHeaderName(InvalidHeaderName),
     InvalidHeaderValue(InvalidHeaderValue),
     HyperError(HyperError),
     HyperClientError(HyperClientError),
@@ -76,6 +78,73 @@ pub enum LavalinkError {
     TrackError(TrackError),
     InvalidDataType,
     Timeout,
+    NodeNotFound,
+    NoNodesLeft,
+    InvalidFilter(String),
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+    QueueRejected(QueueRejection),
+    InvalidEncodedTrack(String),
+    Unauthorized,
+    NotFound(ResponseError),
+    BadRequest(ResponseError),
+    ServerError {
+        status: u16,
+        body: String,
+    },
+    UnexpectedResponse {
+        status: u16,
+        body: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+/// Why tracks were not added to a queue, according to its `QueuePolicy`.
+pub enum QueueRejection {
+    /// The queue would have more than `max_length` tracks.
+    TooManyTracks { max_length: usize },
+    /// The tracks in the queue would last longer than `max_duration`.
+    TooLong { max_duration: std::time::Duration },
+    /// The requester would have more than `max_tracks` tracks in the queue.
+    RequesterQuotaExceeded {
+        requester: serde_json::Value,
+        max_tracks: usize,
+    },
+    /// The track is a stream, and streams are rejected.
+    StreamNotAllowed { identifier: String },
+}
+
+impl Display for QueueRejection {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            QueueRejection::TooManyTracks { max_length } => {
+                write!(f, "the queue cannot have more than {} tracks", max_length)
+            }
+            QueueRejection::TooLong { max_duration } => {
+                write!(
+                    f,
+                    "the queue cannot last longer than {} seconds",
+                    max_duration.as_secs()
+                )
+            }
+            QueueRejection::RequesterQuotaExceeded {
+                requester,
+                max_tracks,
+            } => {
+                write!(
+                    f,
+                    "the requester {} cannot have more than {} tracks in the queue",
+                    requester, max_tracks
+                )
+            }
+            QueueRejection::StreamNotAllowed { identifier } => {
+                write!(f, "the track {} is a stream", identifier)
+            }
+        }
+    }
 }
 
 impl Error for LavalinkError {}
@@ -93,6 +162,9 @@ impl Display for LavalinkError {
             LavalinkError::IoError(why) => {
                 write!(f, "I/O Error => {:?}", why)
             }
+            LavalinkError::InvalidHeaderName(why) => {
+                write!(f, "Invalid Header Name => {:?}", why)
+            }
             LavalinkError::InvalidHeaderValue(why) => {
                 write!(f, "Invalid Header Value => {:?}", why)
             }
@@ -142,10 +214,57 @@ impl Display for LavalinkError {
             LavalinkError::Timeout => {
                 write!(f, "Timeout reached while waiting for response.")
             }
+            LavalinkError::NodeNotFound => {
+                write!(f, "The node was not found.")
+            }
+            LavalinkError::NoNodesLeft => {
+                write!(f, "The client must have at least one node.")
+            }
+            LavalinkError::InvalidFilter(why) => {
+                write!(f, "Invalid filter: {}", why)
+            }
+            LavalinkError::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "Index {} is out of bounds for a queue of length {}.",
+                    index, len
+                )
+            }
+            LavalinkError::QueueRejected(why) => {
+                write!(f, "Tracks rejected from the queue: {}.", why)
+            }
+            LavalinkError::InvalidEncodedTrack(why) => {
+                write!(f, "Invalid encoded track: {}", why)
+            }
+            LavalinkError::Unauthorized => {
+                write!(f, "The password of the lavalink server is wrong.")
+            }
+            LavalinkError::NotFound(why) => {
+                write!(f, "Not found by the lavalink server: {}", why.message)
+            }
+            LavalinkError::BadRequest(why) => {
+                write!(f, "Bad request to the lavalink server: {}", why.message)
+            }
+            LavalinkError::ServerError { status, body } => {
+                write!(f, "Lavalink server error (status {}): {}", status, body)
+            }
+            LavalinkError::UnexpectedResponse { status, body } => {
+                write!(
+                    f,
+                    "Unexpected response from the lavalink server (status {}): {}",
+                    status, body
+                )
+            }
         }
     }
 }
 
+impl From<QueueRejection> for LavalinkError {
+    fn from(err: QueueRejection) -> LavalinkError {
+        LavalinkError::QueueRejected(err)
+    }
+}
+
 impl From<IoError> for LavalinkError {
     fn from(err: IoError) -> LavalinkError {
         LavalinkError::IoError(err)
@@ -178,6 +297,12 @@ impl From<WebsocketsError> for LavalinkError {
     }
 }
 
+impl From<InvalidHeaderName> for LavalinkError {
+    fn from(err: InvalidHeaderName) -> LavalinkError {
+        LavalinkError::InvalidHeaderName(err)
+    }
+}
+
 impl From<InvalidHeaderValue> for LavalinkError {
     fn from(err: InvalidHeaderValue) -> LavalinkError {
         LavalinkError::InvalidHeaderValue(err)
@@ -242,6 +367,13 @@ impl From<serde_json::Error> for LavalinkError {
 impl From<LavalinkError> for PyErr {
     fn from(err: LavalinkError) -> PyErr {
         error!("{}", err);
-        PyErr::new::<PyException, _>(format!("{:?}", err))
+
+        match err {
+            LavalinkError::IndexOutOfBounds { .. } => {
+                PyErr::new::<PyIndexError, _>(err.to_string())
+            }
+            LavalinkError::InvalidEncodedTrack(_) => PyErr::new::<PyValueError, _>(err.to_string()),
+            _ => PyErr::new::<PyException, _>(format!("{:?}", err)),
+        }
     }
 }
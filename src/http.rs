@@ -1,12 +1,49 @@
-use crate::error::LavalinkResult;
+use crate::error::{LavalinkError, LavalinkResult, ResponseError};
 use crate::model::*;
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use ::http::{uri::InvalidUri, Method, Uri};
+use ::http::{uri::InvalidUri, Method, StatusCode, Uri};
 use http_body_util::BodyExt;
 use hyper::{body::Buf, Request};
 use std::io::Read;
+use tokio::sync::Semaphore;
+
+mod connector;
+
+pub use connector::{Connector, ConnectorIo, HttpClient};
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// How the REST requests to a node are made.
+pub struct HttpConfig {
+    /// The time a request can take before failing with `LavalinkError::Timeout`, including the
+    /// retries and the time waiting for `max_concurrent_requests`.
+    ///
+    /// None waits forever.
+    pub timeout: Option<Duration>,
+    /// The amount of times idempotent requests, like `GET` and `DELETE`, are retried after a
+    /// connection error or a server error.
+    pub max_retries: u32,
+    /// The delay before the first retry, doubled after every retry.
+    pub retry_delay: Duration,
+    /// The maximum amount of requests made to the node at the same time.
+    ///
+    /// None doesn't limit the requests.
+    pub max_concurrent_requests: Option<usize>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(30)),
+            max_retries: 2,
+            retry_delay: Duration::from_millis(500),
+            max_concurrent_requests: None,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
@@ -15,15 +52,41 @@ pub struct Http {
     pub rest_address: String,
     pub rest_address_versionless: String,
     pub headers: ::http::header::HeaderMap,
-    pub request_client: Arc<
-        hyper_util::client::legacy::Client<
-            crate::HttpsConnector,
-            http_body_util::Full<bytes::Bytes>,
-        >,
-    >,
+    pub request_client: Arc<HttpClient>,
+    pub config: HttpConfig,
+    pub(crate) request_limiter: Option<Arc<Semaphore>>,
 }
 
 impl Http {
+    /// Create the REST client of a node.
+    ///
+    /// # Parameters
+    ///
+    /// - `hostname`: The hostname of the Lavalink server, like "localhost:2333".
+    /// - `is_ssl`: If the Lavalink server is behind SSL encryption.
+    /// - `headers`: The headers sent with every request.
+    pub fn new(
+        hostname: &str,
+        is_ssl: bool,
+        headers: ::http::header::HeaderMap,
+        request_client: Arc<HttpClient>,
+        config: HttpConfig,
+    ) -> Self {
+        let scheme = if is_ssl { "https" } else { "http" };
+
+        Http {
+            authority: hostname.to_string(),
+            rest_address: format!("{}://{}/v4", scheme, hostname),
+            rest_address_versionless: format!("{}://{}", scheme, hostname),
+            headers,
+            request_client,
+            request_limiter: config
+                .max_concurrent_requests
+                .map(|permits| Arc::new(Semaphore::new(permits))),
+            config,
+        }
+    }
+
     /// Makes an HTTP/1.1 request using Hyper to endpoints that return deserializable data.
     pub async fn request<R: serde::de::DeserializeOwned, T: serde::Serialize + ?Sized, U>(
         &self,
@@ -35,25 +98,7 @@ impl Http {
         Uri: TryFrom<U>,
         <Uri as TryFrom<U>>::Error: Into<::http::Error>,
     {
-        let mut request_builder = Request::builder().method(method).uri(uri);
-
-        {
-            *request_builder.headers_mut().unwrap() = self.headers.clone()
-        }
-
-        request_builder = request_builder.header(::http::header::HOST, &self.authority);
-
-        let request = if let Some(data) = data {
-            request_builder =
-                request_builder.header(::http::header::CONTENT_TYPE, "application/json");
-            request_builder.body(http_body_util::Full::from(serde_json::to_vec(data)?))?
-        } else {
-            request_builder.body(http_body_util::Full::default())?
-        };
-
-        let response = self.request_client.request(request).await?;
-
-        let raw_body = response.collect().await?.aggregate();
+        let raw_body = self.send(method, uri, data).await?;
         let body = serde_json::from_reader(raw_body.reader())?;
 
         Ok(body)
@@ -70,6 +115,92 @@ impl Http {
         Uri: TryFrom<U>,
         <Uri as TryFrom<U>>::Error: Into<::http::Error>,
     {
+        let mut body = "".to_string();
+        let raw_body = self.send(method, uri, data).await?;
+        raw_body.reader().read_to_string(&mut body)?;
+
+        Ok(body)
+    }
+
+    /// Sends the request, and returns the body of the response if the status is successful.
+    ///
+    /// Idempotent requests are retried according to the `HttpConfig`.
+    async fn send<T: serde::Serialize + ?Sized, U>(
+        &self,
+        method: Method,
+        uri: U,
+        data: Option<&T>,
+    ) -> LavalinkResult<impl Buf>
+    where
+        Uri: TryFrom<U>,
+        <Uri as TryFrom<U>>::Error: Into<::http::Error>,
+    {
+        let uri = Uri::try_from(uri).map_err(Into::<::http::Error>::into)?;
+        let body = data
+            .map(serde_json::to_vec)
+            .transpose()?
+            .map(bytes::Bytes::from);
+
+        let request = self.send_with_retries(method, uri, body);
+
+        match self.config.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, request)
+                .await
+                .unwrap_or(Err(LavalinkError::Timeout)),
+            None => request.await,
+        }
+    }
+
+    async fn send_with_retries(
+        &self,
+        method: Method,
+        uri: Uri,
+        body: Option<bytes::Bytes>,
+    ) -> LavalinkResult<impl Buf> {
+        let max_retries = if method.is_idempotent() {
+            self.config.max_retries
+        } else {
+            0
+        };
+
+        let mut retries = 0;
+
+        loop {
+            let result = self
+                .send_once(method.clone(), uri.clone(), body.clone())
+                .await;
+
+            match result {
+                Err(why) if retries < max_retries && Self::is_retryable(&why) => {
+                    let delay = self
+                        .config
+                        .retry_delay
+                        .saturating_mul(2_u32.saturating_pow(retries));
+
+                    warn!(
+                        "Request to {} failed, retrying in {:?}: {}",
+                        uri, delay, why
+                    );
+
+                    tokio::time::sleep(delay).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn send_once(
+        &self,
+        method: Method,
+        uri: Uri,
+        body: Option<bytes::Bytes>,
+    ) -> LavalinkResult<impl Buf> {
+        let _permit = match &self.request_limiter {
+            Some(request_limiter) => request_limiter.acquire().await.ok(),
+            None => None,
+        };
+
         let mut request_builder = Request::builder().method(method).uri(uri);
 
         {
@@ -78,21 +209,62 @@ impl Http {
 
         request_builder = request_builder.header(::http::header::HOST, &self.authority);
 
-        let request = if let Some(data) = data {
+        let request = if let Some(body) = body {
             request_builder =
                 request_builder.header(::http::header::CONTENT_TYPE, "application/json");
-            request_builder.body(http_body_util::Full::from(serde_json::to_vec(data)?))?
+            request_builder.body(http_body_util::Full::from(body))?
         } else {
             request_builder.body(http_body_util::Full::default())?
         };
 
         let response = self.request_client.request(request).await?;
 
-        let mut body = "".to_string();
+        let status = response.status();
         let raw_body = response.collect().await?.aggregate();
-        raw_body.reader().read_to_string(&mut body)?;
 
-        Ok(body)
+        if status.is_success() {
+            Ok(raw_body)
+        } else {
+            Err(Self::status_error(status, raw_body))
+        }
+    }
+
+    fn is_retryable(error: &LavalinkError) -> bool {
+        match error {
+            LavalinkError::HyperClientError(why) => why.is_connect(),
+            LavalinkError::ServerError { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Maps an unsuccessful response to the error that describes it best.
+    fn status_error(status: StatusCode, raw_body: impl Buf) -> LavalinkError {
+        let mut body = Vec::new();
+
+        if let Err(why) = raw_body.reader().read_to_end(&mut body) {
+            return why.into();
+        }
+
+        if status == StatusCode::UNAUTHORIZED {
+            return LavalinkError::Unauthorized;
+        }
+
+        if status.is_server_error() {
+            return LavalinkError::ServerError {
+                status: status.as_u16(),
+                body: String::from_utf8_lossy(&body).into_owned(),
+            };
+        }
+
+        match serde_json::from_slice::<ResponseError>(&body) {
+            Ok(why) if status == StatusCode::NOT_FOUND => LavalinkError::NotFound(why),
+            Ok(why) if status == StatusCode::BAD_REQUEST => LavalinkError::BadRequest(why),
+            Ok(why) => LavalinkError::ResponseError(why),
+            Err(_) => LavalinkError::UnexpectedResponse {
+                status: status.as_u16(),
+                body: String::from_utf8_lossy(&body).into_owned(),
+            },
+        }
     }
 
     /// Convert a path and query to a uri that points to the lavalink server.
@@ -0,0 +1,146 @@
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use ::http::Uri;
+use futures::future::BoxFuture;
+use hyper::rt::{Read, ReadBufCursor, Write};
+use hyper_util::client::legacy::connect::{Connected, Connection};
+use tower_service::Service;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// The REST client of a node, with the connector it opens connections with.
+pub type HttpClient =
+    hyper_util::client::legacy::Client<Connector, http_body_util::Full<bytes::Bytes>>;
+
+trait Io: Read + Write + Connection + Unpin + Send {}
+
+impl<T: Read + Write + Connection + Unpin + Send> Io for T {}
+
+/// A connection opened by a `Connector`.
+pub struct ConnectorIo(Box<dyn Io>);
+
+#[derive(Clone)]
+/// Opens the connections to a Lavalink server, for both the REST requests and the websocket.
+///
+/// It wraps any `hyper_util` connector, like an `HttpsConnector` built with custom root
+/// certificates or client certificates, or a connector that goes through a proxy.
+///
+/// # Example
+///
+/// ```
+/// # use lavalink_rs::http::Connector;
+/// let connector = Connector::new(hyper_util::client::legacy::connect::HttpConnector::new());
+/// ```
+pub struct Connector {
+    connect: Arc<dyn Fn(Uri) -> BoxFuture<'static, Result<ConnectorIo, BoxError>> + Send + Sync>,
+}
+
+impl Connector {
+    /// Wrap a connector.
+    ///
+    /// The connector must handle TLS by itself for `https` URIs.
+    pub fn new<C>(connector: C) -> Self
+    where
+        C: Service<Uri> + Clone + Send + Sync + 'static,
+        C::Response: Read + Write + Connection + Unpin + Send + 'static,
+        C::Future: Send + 'static,
+        C::Error: Into<BoxError>,
+    {
+        Connector {
+            connect: Arc::new(move |uri| {
+                let mut connector = connector.clone();
+
+                Box::pin(async move {
+                    futures::future::poll_fn(|cx| connector.poll_ready(cx))
+                        .await
+                        .map_err(Into::into)?;
+
+                    let io = connector.call(uri).await.map_err(Into::into)?;
+
+                    Ok(ConnectorIo(Box::new(io)))
+                })
+            }),
+        }
+    }
+
+    /// Open a connection to the uri.
+    pub async fn connect(&self, uri: Uri) -> Result<ConnectorIo, BoxError> {
+        (self.connect)(uri).await
+    }
+}
+
+impl std::fmt::Debug for Connector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Connector").finish_non_exhaustive()
+    }
+}
+
+impl Hash for Connector {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.connect) as *const () as usize).hash(state);
+    }
+}
+
+impl Service<Uri> for Connector {
+    type Response = ConnectorIo;
+    type Error = BoxError;
+    type Future = BoxFuture<'static, Result<ConnectorIo, BoxError>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        (self.connect)(uri)
+    }
+}
+
+impl Connection for ConnectorIo {
+    fn connected(&self) -> Connected {
+        self.0.connected()
+    }
+}
+
+impl Read for ConnectorIo {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: ReadBufCursor<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.0).poll_read(cx, buf)
+    }
+}
+
+impl Write for ConnectorIo {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut *self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut *self.0).poll_shutdown(cx)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.0.is_write_vectored()
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut *self.0).poll_write_vectored(cx, bufs)
+    }
+}
@@ -16,14 +16,6 @@ extern crate macro_rules_attribute;
 #[macro_use]
 extern crate serde;
 
-#[cfg(feature = "_rustls-tls")]
-pub(crate) type HttpsConnector =
-    hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>;
-
-#[cfg(feature = "_native-tls")]
-pub(crate) type HttpsConnector =
-    hyper_tls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>;
-
 /// The main client, where everything gets done.
 pub mod client;
 /// Every possible error that the library can return.
@@ -79,6 +71,7 @@ fn lavalink_rs(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<python::http::Http>()?;
     m.add_class::<python::node::Node>()?;
     m.add_class::<node::NodeBuilder>()?;
+    m.add_class::<node::ReconnectPolicy>()?;
     m.add_class::<python::model::client::NodeDistributionStrategyPy>()?;
     m.add_class::<player_context::TrackInQueue>()?;
     m.add_class::<player_context::QueueRef>()?;
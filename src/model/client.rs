@@ -16,6 +16,23 @@ pub(crate) enum ClientMessage {
     StateUpdate(GuildId, Option<ChannelId>, UserId, String), // guild_id, channel_id, user_id, session_id
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+/// The connection state of a node.
+pub enum NodeState {
+    /// The websocket connection is being established.
+    Connecting,
+    /// The websocket is connected and the session is ready to be used.
+    Connected,
+    /// The websocket is connected, and the previous session is being resumed.
+    Resuming,
+    /// The websocket connection was lost, and it will be retried.
+    #[default]
+    Disconnected,
+    /// Every reconnection attempt failed, and no more will be made.
+    Failed,
+}
+
 #[derive(Debug, Default, Clone)]
 pub enum NodeDistributionStrategy {
     #[default]
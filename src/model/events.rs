@@ -1,11 +1,16 @@
 use crate::client::LavalinkClient;
+use crate::error::LavalinkResult;
 use crate::model::*;
 
+use std::sync::Arc;
+
 #[derive(Debug, Clone, Default)]
 #[cfg_attr(not(feature = "python"), derive(Hash))]
 pub struct Events {
     /// Every single event will trigger this event with the raw data received.
     pub raw: Option<fn(LavalinkClient, session_id: String, &serde_json::Value) -> BoxFuture<()>>,
+    /// Dispatched when an event received from Lavalink could not be decoded.
+    pub decode_error: Option<fn(LavalinkClient, session_id: String, &DecodeError) -> BoxFuture<()>>,
     /// Dispatched by Lavalink upon successful connection and authorization.
     pub ready: Option<fn(LavalinkClient, session_id: String, &Ready) -> BoxFuture<()>>,
     /// Dispatched periodically with the current state of a player.
@@ -26,11 +31,490 @@ pub struct Events {
     /// Dispatched when an audio WebSocket to Discord is closed.
     pub websocket_closed:
         Option<fn(LavalinkClient, session_id: String, &WebSocketClosed) -> BoxFuture<()>>,
+    /// Dispatched when the connection state of a node changes.
+    pub node_state_update:
+        Option<fn(LavalinkClient, session_id: String, &NodeStateUpdate) -> BoxFuture<()>>,
+    /// Dispatched when a node is connected with a new session.
+    pub node_connected:
+        Option<fn(LavalinkClient, session_id: String, &NodeConnected) -> BoxFuture<()>>,
+    /// Dispatched when the connection to a node is lost.
+    pub node_disconnected:
+        Option<fn(LavalinkClient, session_id: String, &NodeDisconnected) -> BoxFuture<()>>,
+    /// Dispatched before every attempt to reconnect to a node.
+    pub node_reconnecting:
+        Option<fn(LavalinkClient, session_id: String, &NodeReconnecting) -> BoxFuture<()>>,
+    /// Dispatched when a node is connected and the previous session was resumed.
+    pub node_resumed: Option<fn(LavalinkClient, session_id: String, &NodeResumed) -> BoxFuture<()>>,
+    /// Dispatched when an event unknown to the library is received, like the ones sent by
+    /// plugins, and no handler is registered for its type in `plugin_events`.
+    pub plugin_event: Option<fn(LavalinkClient, session_id: String, &PluginEvent) -> BoxFuture<()>>,
+    /// Typed handlers for plugin events.
+    pub plugin_events: PluginEvents,
+
+    /// A handler implementing [`EventHandler`], called after the function handlers.
+    ///
+    /// Unlike the function handlers, it can hold state, like an `Arc` of the application data.
+    pub handler: Option<SharedEventHandler>,
 
     #[cfg(feature = "python")]
     pub(crate) event_handler: Option<crate::python::event::EventHandler>,
 }
 
+/// A trait to handle the events with methods, as an alternative to the function handlers in
+/// [`Events`].
+///
+/// Every method does nothing by default, so only the events of interest need to be implemented.
+///
+/// # Example
+///
+/// ```
+/// # use std::sync::atomic::{AtomicU64, Ordering};
+/// # use std::sync::Arc;
+/// # use lavalink_rs::client::LavalinkClient;
+/// # use lavalink_rs::model::BoxFuture;
+/// # use lavalink_rs::model::events::{EventHandler, Events, TrackStart};
+/// struct Handler {
+///     tracks_played: Arc<AtomicU64>,
+/// }
+///
+/// impl EventHandler for Handler {
+///     fn track_start<'a>(
+///         &'a self,
+///         _: LavalinkClient,
+///         _: String,
+///         _: &'a TrackStart,
+///     ) -> BoxFuture<'a, ()> {
+///         Box::pin(async move {
+///             self.tracks_played.fetch_add(1, Ordering::Relaxed);
+///         })
+///     }
+/// }
+///
+/// let events = Events {
+///     handler: Some(Handler { tracks_played: Arc::default() }.into()),
+///     ..Default::default()
+/// };
+/// ```
+pub trait EventHandler: Send + Sync {
+    /// Every single event will trigger this event with the raw data received.
+    fn raw<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a serde_json::Value,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when an event received from Lavalink could not be decoded.
+    fn decode_error<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a DecodeError,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched by Lavalink upon successful connection and authorization.
+    fn ready<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a Ready,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched periodically with the current state of a player.
+    fn player_update<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a PlayerUpdate,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// A collection of statistics sent every minute.
+    fn stats<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a Stats,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a track starts playing.
+    fn track_start<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a TrackStart,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a track ends.
+    /// track_exception and track_stuck will also trigger this event.
+    fn track_end<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a TrackEnd,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a track throws an exception.
+    fn track_exception<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a TrackException,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a track gets stuck while playing.
+    fn track_stuck<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a TrackStuck,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when an audio WebSocket to Discord is closed.
+    fn websocket_closed<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a WebSocketClosed,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when the connection state of a node changes.
+    fn node_state_update<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a NodeStateUpdate,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a node is connected with a new session.
+    fn node_connected<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a NodeConnected,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when the connection to a node is lost.
+    fn node_disconnected<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a NodeDisconnected,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched before every attempt to reconnect to a node.
+    fn node_reconnecting<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a NodeReconnecting,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when a node is connected and the previous session was resumed.
+    fn node_resumed<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a NodeResumed,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+
+    /// Dispatched when an event unknown to the library is received, like the ones sent by
+    /// plugins, and no handler is registered for its type in `plugin_events`.
+    fn plugin_event<'a>(
+        &'a self,
+        client: LavalinkClient,
+        session_id: String,
+        event: &'a PluginEvent,
+    ) -> BoxFuture<'a, ()> {
+        let _ = (client, session_id, event);
+        Box::pin(async {})
+    }
+}
+
+#[derive(Clone)]
+/// A shared [`EventHandler`].
+///
+/// Like the function handlers, it's hashed by the address of the handler.
+pub struct SharedEventHandler(pub Arc<dyn EventHandler>);
+
+impl<H: EventHandler + 'static> From<H> for SharedEventHandler {
+    fn from(handler: H) -> Self {
+        Self(Arc::new(handler))
+    }
+}
+
+impl std::fmt::Debug for SharedEventHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedEventHandler")
+            .field(&Arc::as_ptr(&self.0))
+            .finish()
+    }
+}
+
+impl std::hash::Hash for SharedEventHandler {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state)
+    }
+}
+
+/// An event that can be passed to an [`EventHandler`].
+pub(crate) trait HandlerEvent {
+    fn call_handler<'a>(
+        &'a self,
+        handler: &'a dyn EventHandler,
+        client: LavalinkClient,
+        session_id: String,
+    ) -> BoxFuture<'a, ()>;
+}
+
+macro_rules! impl_handler_event {
+    ($($event:ty => $method:ident,)*) => {
+        $(
+            impl HandlerEvent for $event {
+                fn call_handler<'a>(
+                    &'a self,
+                    handler: &'a dyn EventHandler,
+                    client: LavalinkClient,
+                    session_id: String,
+                ) -> BoxFuture<'a, ()> {
+                    handler.$method(client, session_id, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_handler_event! {
+    serde_json::Value => raw,
+    DecodeError => decode_error,
+    Ready => ready,
+    PlayerUpdate => player_update,
+    Stats => stats,
+    TrackStart => track_start,
+    TrackEnd => track_end,
+    TrackException => track_exception,
+    TrackStuck => track_stuck,
+    WebSocketClosed => websocket_closed,
+    NodeStateUpdate => node_state_update,
+    NodeConnected => node_connected,
+    NodeDisconnected => node_disconnected,
+    NodeReconnecting => node_reconnecting,
+    NodeResumed => node_resumed,
+    PluginEvent => plugin_event,
+}
+
+type PluginEventHandler = dyn Fn(
+        LavalinkClient,
+        String,
+        &serde_json::Value,
+    ) -> Result<BoxFuture<'static, ()>, serde_json::Error>
+    + Send
+    + Sync;
+
+#[derive(Clone, Default)]
+/// Typed handlers for the events sent by plugins, registered by the `type` of the event.
+///
+/// # Example
+///
+/// ```
+/// # use lavalink_rs::client::LavalinkClient;
+/// # use lavalink_rs::model::BoxFuture;
+/// # use lavalink_rs::model::events::{Events, PluginEvents};
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct SegmentsLoaded {
+///     guild_id: String,
+///     segments: Vec<serde_json::Value>,
+/// }
+///
+/// fn segments_loaded(
+///     _: LavalinkClient,
+///     _: String,
+///     event: &SegmentsLoaded,
+/// ) -> BoxFuture<()> {
+///     Box::pin(async move {
+///         println!("{} segments loaded in {}", event.segments.len(), event.guild_id);
+///     })
+/// }
+///
+/// let events = Events {
+///     plugin_events: PluginEvents::default().register("SegmentsLoaded", segments_loaded),
+///     ..Default::default()
+/// };
+/// ```
+pub struct PluginEvents(std::collections::BTreeMap<String, Arc<PluginEventHandler>>);
+
+impl PluginEvents {
+    /// Register a handler for the plugin events with the provided `type`, decoding them into `T`.
+    ///
+    /// If an event fails to decode, the `decode_error` event is dispatched instead.
+    pub fn register<T>(
+        mut self,
+        event_type: impl Into<String>,
+        handler: fn(LavalinkClient, session_id: String, &T) -> BoxFuture<()>,
+    ) -> Self
+    where
+        T: de::DeserializeOwned + Send + Sync + 'static,
+    {
+        let handler = move |client, session_id, raw: &serde_json::Value| {
+            let event = T::deserialize(raw)?;
+            let future: BoxFuture<'static, ()> =
+                Box::pin(async move { handler(client, session_id, &event).await });
+
+            Ok(future)
+        };
+
+        self.0.insert(event_type.into(), Arc::new(handler));
+        self
+    }
+
+    pub(crate) fn get(&self, event_type: &str) -> Option<&PluginEventHandler> {
+        self.0.get(event_type).map(|handler| &**handler)
+    }
+}
+
+impl std::fmt::Debug for PluginEvents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl std::hash::Hash for PluginEvents {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        for (event_type, handler) in &self.0 {
+            event_type.hash(state);
+            Arc::as_ptr(handler).cast::<()>().hash(state);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An event received from the Lavalink websocket, decoded into its type.
+pub enum LavalinkEvent {
+    Ready(Ready),
+    PlayerUpdate(PlayerUpdate),
+    Stats(Stats),
+    TrackStart(TrackStart),
+    TrackEnd(TrackEnd),
+    TrackException(TrackException),
+    TrackStuck(TrackStuck),
+    WebSocketClosed(WebSocketClosed),
+    /// An event with an `op` or `type` unknown to the library, like the ones sent by plugins.
+    Unknown(serde_json::Value),
+}
+
+impl LavalinkEvent {
+    /// Decode a raw event received from the Lavalink websocket.
+    ///
+    /// Returns `LavalinkError::SerdeErrorJson` if the `op` or `type` fields are missing, or if
+    /// the event does not match the schema of its type.
+    pub fn decode(raw: &serde_json::Value) -> LavalinkResult<Self> {
+        fn get_str<'a>(raw: &'a serde_json::Value, field: &'static str) -> LavalinkResult<&'a str> {
+            raw.get(field)
+                .and_then(serde_json::Value::as_str)
+                .ok_or_else(|| <serde_json::Error as de::Error>::missing_field(field).into())
+        }
+
+        let event = match get_str(raw, "op")? {
+            "ready" => Self::Ready(Deserialize::deserialize(raw)?),
+            "playerUpdate" => Self::PlayerUpdate(Deserialize::deserialize(raw)?),
+            "stats" => Self::Stats(Deserialize::deserialize(raw)?),
+            "event" => match get_str(raw, "type")? {
+                "TrackStartEvent" => Self::TrackStart(Deserialize::deserialize(raw)?),
+                "TrackEndEvent" => Self::TrackEnd(Deserialize::deserialize(raw)?),
+                "TrackExceptionEvent" => Self::TrackException(Deserialize::deserialize(raw)?),
+                "TrackStuckEvent" => Self::TrackStuck(Deserialize::deserialize(raw)?),
+                "WebSocketClosedEvent" => Self::WebSocketClosed(Deserialize::deserialize(raw)?),
+                _ => Self::Unknown(raw.clone()),
+            },
+            _ => Self::Unknown(raw.clone()),
+        };
+
+        Ok(event)
+    }
+
+    /// The ID of the guild the event belongs to, if any.
+    pub fn guild_id(&self) -> Option<GuildId> {
+        match self {
+            Self::PlayerUpdate(event) => Some(event.guild_id),
+            Self::TrackStart(event) => Some(event.guild_id),
+            Self::TrackEnd(event) => Some(event.guild_id),
+            Self::TrackException(event) => Some(event.guild_id),
+            Self::TrackStuck(event) => Some(event.guild_id),
+            Self::WebSocketClosed(event) => Some(event.guild_id),
+            Self::Unknown(raw) => raw_guild_id(raw),
+            Self::Ready(_) | Self::Stats(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// An event yielded by the event streams, with the node it was received from.
+pub struct NodeEvent {
+    /// The ID of the node that sent the event.
+    pub node_id: usize,
+    pub event: LavalinkEvent,
+}
+
+fn raw_guild_id(raw: &serde_json::Value) -> Option<GuildId> {
+    raw.get("guildId")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|guild_id| guild_id.parse().ok())
+}
+
+#[derive(Debug, Clone)]
+/// Dispatched when an event received from Lavalink could not be decoded.
+pub struct DecodeError {
+    /// The raw event that failed to decode.
+    pub raw: serde_json::Value,
+    /// The error returned while decoding the event.
+    pub error: Arc<serde_json::Error>,
+}
+
 #[derive(Hash, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
@@ -218,3 +702,84 @@ pub struct WebSocketClosed {
     /// Whether the connection was closed by Discord or not.
     pub by_remote: bool,
 }
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Dispatched when the connection state of a node changes.
+pub struct NodeStateUpdate {
+    /// The ID of the node.
+    pub node_id: usize,
+    /// The state the node was in before.
+    pub old_state: client::NodeState,
+    /// The state the node is in now.
+    pub new_state: client::NodeState,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+/// Dispatched when an event unknown to the library is received, like the ones sent by plugins.
+pub struct PluginEvent {
+    /// The `type` of the event, or its `op` if it has no type.
+    pub event_type: String,
+    /// The guild the event belongs to, if any.
+    pub guild_id: Option<GuildId>,
+    /// The raw event.
+    pub raw: serde_json::Value,
+}
+
+impl PluginEvent {
+    pub(crate) fn from_raw(raw: serde_json::Value) -> Self {
+        let event_type = raw
+            .get("type")
+            .or_else(|| raw.get("op"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        PluginEvent {
+            event_type,
+            guild_id: raw_guild_id(&raw),
+            raw,
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Dispatched when a node is connected with a new session.
+pub struct NodeConnected {
+    /// The ID of the node.
+    pub node_id: usize,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Dispatched when the connection to a node is lost.
+pub struct NodeDisconnected {
+    /// The ID of the node.
+    pub node_id: usize,
+    /// The websocket close code sent by the node, if the connection was closed cleanly.
+    pub code: Option<u16>,
+    /// The reason the connection was closed.
+    pub reason: String,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Dispatched before every attempt to reconnect to a node.
+pub struct NodeReconnecting {
+    /// The ID of the node.
+    pub node_id: usize,
+    /// The number of this attempt, starting from 1.
+    pub attempt: u32,
+    /// The time waited before this attempt in milliseconds.
+    pub delay_ms: u64,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Dispatched when a node is connected and the previous session was resumed.
+pub struct NodeResumed {
+    /// The ID of the node.
+    pub node_id: usize,
+}
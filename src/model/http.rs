@@ -42,6 +42,25 @@ pub struct UpdatePlayer {
     pub voice: Option<player::ConnectionInfo>,
 }
 
+impl From<player::Player> for UpdatePlayer {
+    /// Recreate a player with the same track, position, volume, filters and voice state.
+    fn from(player: player::Player) -> Self {
+        UpdatePlayer {
+            position: player.track.as_ref().map(|_| player.state.position),
+            track: player.track.map(|track| UpdatePlayerTrack {
+                encoded: Some(track.encoded),
+                user_data: track.user_data,
+                ..Default::default()
+            }),
+            volume: Some(player.volume),
+            paused: Some(player.paused),
+            filters: player.filters,
+            voice: Some(player.voice),
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
@@ -163,7 +163,7 @@ where
     }
 }
 
-pub(crate) fn serialize_string_from_option_trait<'de, T, S>(
+pub(crate) fn serialize_string_from_option_trait<T, S>(
     value: &Option<T>,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
@@ -1,3 +1,4 @@
+use crate::error::{LavalinkError, LavalinkResult};
 use crate::model::*;
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -240,13 +241,13 @@ pub struct Rotation {
 ///
 /// All default to 1.0.
 pub struct Timescale {
-    /// The playback speed (0.0 <= x)
+    /// The playback speed (0.0 < x)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
-    /// The pitch (0.0 <= x)
+    /// The pitch (0.0 < x)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pitch: Option<f64>,
-    /// The rate (0.0 <= x)
+    /// The rate (0.0 < x)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rate: Option<f64>,
 }
@@ -268,3 +269,594 @@ pub struct TremoloVibrato {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub depth: Option<f64>,
 }
+
+/// Merges the fields that are set in `other` into `self`.
+trait Merge {
+    fn merge(&mut self, other: Self);
+}
+
+macro_rules! impl_merge {
+    ($($filter:ty { $($field:ident),* $(,)? })*) => {
+        $(
+            impl Merge for $filter {
+                fn merge(&mut self, other: Self) {
+                    $(
+                        if other.$field.is_some() {
+                            self.$field = other.$field;
+                        }
+                    )*
+                }
+            }
+        )*
+    };
+}
+
+impl_merge! {
+    ChannelMix { left_to_left, left_to_right, right_to_left, right_to_right }
+    Distortion { sin_offset, sin_scale, cos_offset, cos_scale, tan_offset, tan_scale, offset, scale }
+    Karaoke { level, mono_level, filter_band, filter_width }
+    LowPass { smoothing }
+    Rotation { rotation_hz }
+    Timescale { speed, pitch, rate }
+    TremoloVibrato { frequency, depth }
+}
+
+fn merge_option<T: Merge>(current: &mut Option<T>, other: Option<T>) {
+    match (current.as_mut(), other) {
+        (Some(current), Some(other)) => current.merge(other),
+        (None, Some(other)) => *current = Some(other),
+        (_, None) => (),
+    }
+}
+
+/// Interpolates every field between `self` and `other`.
+trait Interpolate {
+    fn interpolate(&self, other: &Self, t: f64) -> Self;
+}
+
+/// Interpolates a field, using the value that has no effect in place of a missing one.
+///
+/// Fields without such a value keep the value that is set until the end of the transition.
+fn interpolate_value(
+    from: Option<f64>,
+    to: Option<f64>,
+    neutral: Option<f64>,
+    t: f64,
+) -> Option<f64> {
+    if from.is_none() && to.is_none() {
+        return None;
+    }
+
+    match (from.or(neutral), to.or(neutral)) {
+        (Some(from), Some(to)) => Some(from + (to - from) * t),
+        _ => from,
+    }
+}
+
+macro_rules! impl_interpolate {
+    ($($filter:ty { $($field:ident $(= $neutral:expr)?),* $(,)? })*) => {
+        $(
+            impl Interpolate for $filter {
+                fn interpolate(&self, other: &Self, t: f64) -> Self {
+                    Self {
+                        $(
+                            $field: interpolate_value(
+                                self.$field,
+                                other.$field,
+                                None$(.or(Some($neutral)))?,
+                                t,
+                            ),
+                        )*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate! {
+    ChannelMix { left_to_left = 1.0, left_to_right = 0.0, right_to_left = 0.0, right_to_right = 1.0 }
+    Distortion {
+        sin_offset = 0.0, sin_scale = 1.0, cos_offset = 0.0, cos_scale = 1.0,
+        tan_offset = 0.0, tan_scale = 1.0, offset = 0.0, scale = 1.0,
+    }
+    Karaoke { level = 0.0, mono_level = 0.0, filter_band, filter_width }
+    LowPass { smoothing }
+    Rotation { rotation_hz = 0.0 }
+    Timescale { speed = 1.0, pitch = 1.0, rate = 1.0 }
+    TremoloVibrato { frequency, depth }
+}
+
+fn interpolate_option<T: Interpolate + Default + PartialEq>(
+    from: &Option<T>,
+    to: &Option<T>,
+    t: f64,
+) -> Option<T> {
+    if from.is_none() && to.is_none() {
+        return None;
+    }
+
+    let default = T::default();
+
+    let filter = from
+        .as_ref()
+        .unwrap_or(&default)
+        .interpolate(to.as_ref().unwrap_or(&default), t);
+
+    // A missing filter with only fields that have no neutral value stays missing until the end.
+    if from.is_none() && filter == default {
+        None
+    } else {
+        Some(filter)
+    }
+}
+
+fn check_range(
+    filter: &str,
+    value: Option<f64>,
+    range: impl std::ops::RangeBounds<f64> + std::fmt::Debug,
+) -> LavalinkResult<()> {
+    match value {
+        Some(value) if !range.contains(&value) => Err(LavalinkError::InvalidFilter(format!(
+            "{} must be in the range {:?}, got {}",
+            filter, range, value
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn check_positive(filter: &str, value: Option<f64>) -> LavalinkResult<()> {
+    match value {
+        Some(value) if value.is_nan() || value <= 0.0 => Err(LavalinkError::InvalidFilter(
+            format!("{} must be greater than 0, got {}", filter, value),
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl Filters {
+    /// Merge the filters that are set in `other` into these filters.
+    ///
+    /// Equalizer bands are replaced one by one, and plugin filters are replaced by name.
+    pub fn merge(&mut self, other: Filters) {
+        if other.volume.is_some() {
+            self.volume = other.volume;
+        }
+
+        if let Some(bands) = other.equalizer {
+            let equalizer = self.equalizer.get_or_insert_with(Vec::new);
+
+            for band in bands {
+                equalizer.retain(|x| x.band != band.band);
+                equalizer.push(band);
+            }
+
+            equalizer.sort_by_key(|x| x.band);
+        }
+
+        merge_option(&mut self.karaoke, other.karaoke);
+        merge_option(&mut self.timescale, other.timescale);
+        merge_option(&mut self.tremolo, other.tremolo);
+        merge_option(&mut self.vibrato, other.vibrato);
+        merge_option(&mut self.rotation, other.rotation);
+        merge_option(&mut self.distortion, other.distortion);
+        merge_option(&mut self.channel_mix, other.channel_mix);
+        merge_option(&mut self.low_pass, other.low_pass);
+
+        match (&mut self.plugin_filters, other.plugin_filters) {
+            (Some(serde_json::Value::Object(current)), Some(serde_json::Value::Object(other))) => {
+                current.extend(other)
+            }
+            (current, Some(other)) => *current = Some(other),
+            (_, None) => (),
+        }
+    }
+
+    /// Interpolate the filters between these filters and `target`, where `t` goes from 0.0 to 1.0.
+    ///
+    /// The numeric fields set on either side are interpolated, starting from or ending at the
+    /// value that has no effect if they are missing. The fields without such a value, and the
+    /// plugin filters, keep their current value until `t` reaches 1.0, where `target` is returned.
+    pub fn interpolate(&self, target: &Filters, t: f64) -> Filters {
+        if t >= 1.0 {
+            return target.clone();
+        }
+
+        let equalizer = match (&self.equalizer, &target.equalizer) {
+            (None, None) => None,
+            (from, to) => {
+                let gain = |bands: &Option<Vec<Equalizer>>, band| {
+                    bands
+                        .iter()
+                        .flatten()
+                        .find(|x| x.band == band)
+                        .map(|x| x.gain)
+                };
+
+                let mut bands = from
+                    .iter()
+                    .chain(to)
+                    .flatten()
+                    .map(|x| x.band)
+                    .collect::<Vec<_>>();
+                bands.sort();
+                bands.dedup();
+
+                Some(
+                    bands
+                        .into_iter()
+                        .map(|band| Equalizer {
+                            band,
+                            gain: interpolate_value(gain(from, band), gain(to, band), Some(0.0), t)
+                                .unwrap_or_default(),
+                        })
+                        .collect(),
+                )
+            }
+        };
+
+        Filters {
+            volume: interpolate_value(self.volume, target.volume, Some(1.0), t),
+            equalizer,
+            karaoke: interpolate_option(&self.karaoke, &target.karaoke, t),
+            timescale: interpolate_option(&self.timescale, &target.timescale, t),
+            tremolo: interpolate_option(&self.tremolo, &target.tremolo, t),
+            vibrato: interpolate_option(&self.vibrato, &target.vibrato, t),
+            rotation: interpolate_option(&self.rotation, &target.rotation, t),
+            distortion: interpolate_option(&self.distortion, &target.distortion, t),
+            channel_mix: interpolate_option(&self.channel_mix, &target.channel_mix, t),
+            low_pass: interpolate_option(&self.low_pass, &target.low_pass, t),
+            plugin_filters: self.plugin_filters.clone(),
+        }
+    }
+
+    /// Check that every filter that is set is in its valid range.
+    ///
+    /// Returns `LavalinkError::InvalidFilter` with the first invalid value otherwise.
+    pub fn validate(&self) -> LavalinkResult<()> {
+        check_range("volume", self.volume, 0.0..=5.0)?;
+
+        for band in self.equalizer.iter().flatten() {
+            if band.band > 14 {
+                return Err(LavalinkError::InvalidFilter(format!(
+                    "equalizer band must be in the range 0..=14, got {}",
+                    band.band
+                )));
+            }
+
+            check_range("equalizer gain", Some(band.gain), -0.25..=1.0)?;
+        }
+
+        if let Some(karaoke) = &self.karaoke {
+            check_range("karaoke level", karaoke.level, 0.0..=1.0)?;
+            check_range("karaoke mono level", karaoke.mono_level, 0.0..=1.0)?;
+            check_range("karaoke filter band", karaoke.filter_band, 0.0..)?;
+            check_range("karaoke filter width", karaoke.filter_width, 0.0..)?;
+        }
+
+        if let Some(timescale) = &self.timescale {
+            check_positive("timescale speed", timescale.speed)?;
+            check_positive("timescale pitch", timescale.pitch)?;
+            check_positive("timescale rate", timescale.rate)?;
+        }
+
+        if let Some(tremolo) = &self.tremolo {
+            check_positive("tremolo frequency", tremolo.frequency)?;
+            check_positive("tremolo depth", tremolo.depth)?;
+            check_range("tremolo depth", tremolo.depth, ..=1.0)?;
+        }
+
+        if let Some(vibrato) = &self.vibrato {
+            check_positive("vibrato frequency", vibrato.frequency)?;
+            check_range("vibrato frequency", vibrato.frequency, ..=14.0)?;
+            check_positive("vibrato depth", vibrato.depth)?;
+            check_range("vibrato depth", vibrato.depth, ..=1.0)?;
+        }
+
+        if let Some(channel_mix) = &self.channel_mix {
+            check_range(
+                "channel mix left to left",
+                channel_mix.left_to_left,
+                0.0..=1.0,
+            )?;
+            check_range(
+                "channel mix left to right",
+                channel_mix.left_to_right,
+                0.0..=1.0,
+            )?;
+            check_range(
+                "channel mix right to left",
+                channel_mix.right_to_left,
+                0.0..=1.0,
+            )?;
+            check_range(
+                "channel mix right to right",
+                channel_mix.right_to_right,
+                0.0..=1.0,
+            )?;
+        }
+
+        if let Some(low_pass) = &self.low_pass {
+            if let Some(smoothing) = low_pass.smoothing {
+                if smoothing.is_nan() || smoothing <= 1.0 {
+                    return Err(LavalinkError::InvalidFilter(format!(
+                        "low pass smoothing must be greater than 1, got {}",
+                        smoothing
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Default)]
+/// A builder for [`Filters`], that merges every filter added into the previous ones and validates
+/// the result.
+///
+/// # Example
+///
+/// ```
+/// # use lavalink_rs::model::player::{FilterPreset, FiltersBuilder, Timescale};
+/// let filters = FiltersBuilder::new()
+///     .preset(FilterPreset::BassBoost)
+///     .equalizer_band(0, 0.3)
+///     .timescale(Timescale {
+///         speed: Some(1.1),
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(filters.equalizer.unwrap()[0].gain, 0.3);
+/// ```
+pub struct FiltersBuilder {
+    filters: Filters,
+}
+
+impl FiltersBuilder {
+    /// Create a builder without any filters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the volume, from 0.0 to 5.0.
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.filters.volume = Some(volume);
+        self
+    }
+
+    /// Set the gain of a single equalizer band, keeping the other bands.
+    pub fn equalizer_band(self, band: u8, gain: f64) -> Self {
+        self.equalizer([Equalizer { band, gain }])
+    }
+
+    /// Set the gain of multiple equalizer bands, keeping the other bands.
+    pub fn equalizer(self, bands: impl IntoIterator<Item = Equalizer>) -> Self {
+        self.merge(Filters {
+            equalizer: Some(bands.into_iter().collect()),
+            ..Default::default()
+        })
+    }
+
+    /// Set the karaoke filter, keeping the values that are not set.
+    pub fn karaoke(self, karaoke: Karaoke) -> Self {
+        self.merge(Filters {
+            karaoke: Some(karaoke),
+            ..Default::default()
+        })
+    }
+
+    /// Set the timescale filter, keeping the values that are not set.
+    pub fn timescale(self, timescale: Timescale) -> Self {
+        self.merge(Filters {
+            timescale: Some(timescale),
+            ..Default::default()
+        })
+    }
+
+    /// Set the tremolo filter, keeping the values that are not set.
+    pub fn tremolo(self, tremolo: TremoloVibrato) -> Self {
+        self.merge(Filters {
+            tremolo: Some(tremolo),
+            ..Default::default()
+        })
+    }
+
+    /// Set the vibrato filter, keeping the values that are not set.
+    pub fn vibrato(self, vibrato: TremoloVibrato) -> Self {
+        self.merge(Filters {
+            vibrato: Some(vibrato),
+            ..Default::default()
+        })
+    }
+
+    /// Set the rotation filter.
+    pub fn rotation(self, rotation: Rotation) -> Self {
+        self.merge(Filters {
+            rotation: Some(rotation),
+            ..Default::default()
+        })
+    }
+
+    /// Set the distortion filter, keeping the values that are not set.
+    pub fn distortion(self, distortion: Distortion) -> Self {
+        self.merge(Filters {
+            distortion: Some(distortion),
+            ..Default::default()
+        })
+    }
+
+    /// Set the channel mix filter, keeping the values that are not set.
+    pub fn channel_mix(self, channel_mix: ChannelMix) -> Self {
+        self.merge(Filters {
+            channel_mix: Some(channel_mix),
+            ..Default::default()
+        })
+    }
+
+    /// Set the low pass filter.
+    pub fn low_pass(self, low_pass: LowPass) -> Self {
+        self.merge(Filters {
+            low_pass: Some(low_pass),
+            ..Default::default()
+        })
+    }
+
+    /// Set the configuration of a plugin filter, keeping the other plugin filters.
+    pub fn plugin_filter(self, name: impl Into<String>, config: serde_json::Value) -> Self {
+        let mut plugin_filters = serde_json::Map::new();
+        plugin_filters.insert(name.into(), config);
+
+        self.merge(Filters {
+            plugin_filters: Some(serde_json::Value::Object(plugin_filters)),
+            ..Default::default()
+        })
+    }
+
+    /// Add the filters of a preset.
+    pub fn preset(self, preset: FilterPreset) -> Self {
+        self.merge(preset.filters())
+    }
+
+    /// Merge the filters that are set in `filters` into the builder.
+    pub fn merge(mut self, filters: Filters) -> Self {
+        self.filters.merge(filters);
+        self
+    }
+
+    /// Validate and build the filters.
+    pub fn build(self) -> LavalinkResult<Filters> {
+        self.filters.validate()?;
+        Ok(self.filters)
+    }
+}
+
+impl From<Filters> for FiltersBuilder {
+    /// Create a builder that merges new filters into the provided ones.
+    fn from(filters: Filters) -> Self {
+        Self { filters }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int, str))]
+#[non_exhaustive]
+/// Named filter combinations.
+pub enum FilterPreset {
+    /// Boosts the lower frequencies.
+    BassBoost,
+    /// Speeds up the track and raises the pitch.
+    Nightcore,
+    /// Slows down the track and lowers the pitch.
+    Vaporwave,
+    /// Rotates the audio around the listener.
+    EightD,
+    /// Removes most of the vocals.
+    Karaoke,
+    /// Suppresses the higher frequencies.
+    Soft,
+}
+
+impl FilterPreset {
+    /// Every preset, in the order they should be listed.
+    pub const ALL: &'static [FilterPreset] = &[
+        FilterPreset::BassBoost,
+        FilterPreset::Nightcore,
+        FilterPreset::Vaporwave,
+        FilterPreset::EightD,
+        FilterPreset::Karaoke,
+        FilterPreset::Soft,
+    ];
+
+    /// The name of the preset.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FilterPreset::BassBoost => "bassboost",
+            FilterPreset::Nightcore => "nightcore",
+            FilterPreset::Vaporwave => "vaporwave",
+            FilterPreset::EightD => "8d",
+            FilterPreset::Karaoke => "karaoke",
+            FilterPreset::Soft => "soft",
+        }
+    }
+
+    /// The filters applied by the preset.
+    pub fn filters(&self) -> Filters {
+        match self {
+            FilterPreset::BassBoost => Filters {
+                equalizer: Some(
+                    [0.25, 0.2, 0.15, 0.1, 0.05]
+                        .into_iter()
+                        .enumerate()
+                        .map(|(band, gain)| Equalizer {
+                            band: band as u8,
+                            gain,
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            },
+            FilterPreset::Nightcore => Filters {
+                timescale: Some(Timescale {
+                    speed: Some(1.25),
+                    pitch: Some(1.25),
+                    rate: Some(1.0),
+                }),
+                ..Default::default()
+            },
+            FilterPreset::Vaporwave => Filters {
+                equalizer: Some(vec![
+                    Equalizer { band: 0, gain: 0.3 },
+                    Equalizer { band: 1, gain: 0.3 },
+                ]),
+                timescale: Some(Timescale {
+                    speed: Some(0.85),
+                    pitch: Some(0.8),
+                    rate: Some(1.0),
+                }),
+                ..Default::default()
+            },
+            FilterPreset::EightD => Filters {
+                rotation: Some(Rotation {
+                    rotation_hz: Some(0.2),
+                }),
+                ..Default::default()
+            },
+            FilterPreset::Karaoke => Filters {
+                karaoke: Some(Karaoke {
+                    level: Some(1.0),
+                    mono_level: Some(1.0),
+                    filter_band: Some(220.0),
+                    filter_width: Some(100.0),
+                }),
+                ..Default::default()
+            },
+            FilterPreset::Soft => Filters {
+                low_pass: Some(LowPass {
+                    smoothing: Some(20.0),
+                }),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for FilterPreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for FilterPreset {
+    type Err = LavalinkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FilterPreset::ALL
+            .iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| LavalinkError::InvalidFilter(format!("unknown preset {}", s)))
+    }
+}
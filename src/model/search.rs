@@ -23,21 +23,23 @@ pub enum SearchEngines {
     FloweryTTS(FloweryTTSParameters),
 }
 
-impl ToString for SearchEngines {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for SearchEngines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use SearchEngines::*;
-        match self {
-            YouTube => "ytsearch".to_string(),
-            YouTubeMusic => "ytmsearch".to_string(),
-            SoundCloud => "scsearch".to_string(),
-            Spotify => "spsearch".to_string(),
-            SpotifyRecommended(_) => "sprec".to_string(),
-            AppleMusic => "amsearch".to_string(),
-            Deezer => "dzsearch".to_string(),
-            DeezerISRC => "dzisrc".to_string(),
-            YandexMusic => "ymsearch".to_string(),
-            FloweryTTS(_) => "ftts://".to_string(),
-        }
+        let prefix = match self {
+            YouTube => "ytsearch",
+            YouTubeMusic => "ytmsearch",
+            SoundCloud => "scsearch",
+            Spotify => "spsearch",
+            SpotifyRecommended(_) => "sprec",
+            AppleMusic => "amsearch",
+            Deezer => "dzsearch",
+            DeezerISRC => "dzisrc",
+            YandexMusic => "ymsearch",
+            FloweryTTS(_) => "ftts://",
+        };
+
+        f.write_str(prefix)
     }
 }
 
@@ -45,7 +47,7 @@ impl SearchEngines {
     /// Create a String you can pip to `load_tracks()` to get the search results.
     ///
     /// Example:
-    /// ```rust,untested
+    /// ```rust,ignore
     /// let query = SearchEngines::YouTubeMusic.to_query("Ne Obliviscaris - Forget Not").unwrap();
     /// lavalink_client.load_tracks(guild_id, query).await?;
     /// ```
@@ -53,14 +55,14 @@ impl SearchEngines {
         use SearchEngines::*;
         match self {
             YouTube | YouTubeMusic | SoundCloud | Spotify | AppleMusic | Deezer | DeezerISRC
-            | YandexMusic => Ok(format!("{}:{}", self.to_string(), base_query)),
+            | YandexMusic => Ok(format!("{}:{}", self, base_query)),
             SpotifyRecommended(x) => {
                 let query = serde_qs::to_string(&x)?;
-                Ok(format!("{}{}?{}", self.to_string(), base_query, query))
+                Ok(format!("{}{}?{}", self, base_query, query))
             }
             FloweryTTS(x) => {
                 let query = serde_qs::to_string(&x)?;
-                Ok(format!("{}{}?{}", self.to_string(), base_query, query))
+                Ok(format!("{}{}?{}", self, base_query, query))
             }
         }
     }
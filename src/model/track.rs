@@ -1,5 +1,7 @@
 use crate::model::deserialize_option_number;
 
+pub mod encoded;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all, eq, eq_int))]
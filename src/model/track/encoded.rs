@@ -0,0 +1,385 @@
+//! Decoding and encoding of lavaplayer track blobs without a Lavalink node.
+//!
+//! `TrackData::encoded` is a base64 encoded lavaplayer message: a header with the message
+//! flags and size, the format version, and the track info written with Java's `DataOutput`.
+//! Versions 1 to 3 of the format are supported.
+
+use crate::error::{LavalinkError, LavalinkResult};
+use crate::model::track::{TrackData, TrackInfo};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// The newest version of the track format, used when encoding track info.
+pub const LATEST_VERSION: u8 = 3;
+
+const FLAG_VERSIONED: u32 = 1;
+const SIZE_MASK: u32 = 0x3FFF_FFFF;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+/// A track blob decoded into its parts.
+pub struct EncodedTrack {
+    /// The version of the track format, from 1 to 3.
+    pub version: u8,
+    /// Info and metadata about the track.
+    pub info: TrackInfo,
+    /// Data written by the source manager of the track, like the container of HTTP tracks.
+    ///
+    /// Lavaplayer needs this data to play tracks from some sources, so it is kept as is.
+    pub source_data: Vec<u8>,
+}
+
+impl EncodedTrack {
+    /// Decode a base64 encoded track.
+    ///
+    /// NOTE: The track does not store whether it's seekable, so `is_seekable` is inferred as
+    /// `!is_stream`, which matches what Lavalink reports for most sources.
+    pub fn decode(encoded: &str) -> LavalinkResult<Self> {
+        let bytes = STANDARD
+            .decode(encoded.trim())
+            .map_err(|why| invalid(format!("invalid base64: {}", why)))?;
+
+        Self::decode_bytes(&bytes)
+    }
+
+    /// Decode a track from the raw bytes of the message.
+    pub fn decode_bytes(bytes: &[u8]) -> LavalinkResult<Self> {
+        let mut reader = Reader { bytes, offset: 0 };
+
+        let header = reader.read_u32()?;
+        let flags = header >> 30;
+        let size = (header & SIZE_MASK) as usize;
+
+        if reader.remaining() < size {
+            return Err(invalid(format!(
+                "the message is {} bytes long, but only {} bytes are left",
+                size,
+                reader.remaining()
+            )));
+        }
+
+        let mut reader = Reader {
+            bytes: &bytes[4..4 + size],
+            offset: 0,
+        };
+
+        let version = if flags & FLAG_VERSIONED != 0 {
+            reader.read_u8()?
+        } else {
+            1
+        };
+
+        if !(1..=LATEST_VERSION).contains(&version) {
+            return Err(invalid(format!("unsupported version {}", version)));
+        }
+
+        let title = reader.read_utf()?;
+        let author = reader.read_utf()?;
+        let length = reader.read_u64()?;
+        let identifier = reader.read_utf()?;
+        let is_stream = reader.read_bool()?;
+        let uri = if version >= 2 {
+            reader.read_nullable_utf()?
+        } else {
+            None
+        };
+        let (artwork_url, isrc) = if version >= 3 {
+            (reader.read_nullable_utf()?, reader.read_nullable_utf()?)
+        } else {
+            (None, None)
+        };
+        let source_name = reader.read_utf()?;
+
+        let source_data = reader.take(reader.remaining().saturating_sub(8))?.to_vec();
+        let position = reader.read_u64()?;
+
+        Ok(EncodedTrack {
+            version,
+            info: TrackInfo {
+                identifier,
+                // Not stored in the track, see `decode()`.
+                is_seekable: !is_stream,
+                author,
+                length,
+                is_stream,
+                position,
+                title,
+                uri,
+                artwork_url,
+                isrc,
+                source_name,
+            },
+            source_data,
+        })
+    }
+
+    /// Encode the track into base64.
+    ///
+    /// Fails if the track info has fields the version does not support, or if a string or
+    /// number cannot be represented in the format.
+    pub fn encode(&self) -> LavalinkResult<String> {
+        Ok(STANDARD.encode(self.encode_bytes()?))
+    }
+
+    /// Encode the track into the raw bytes of the message.
+    pub fn encode_bytes(&self) -> LavalinkResult<Vec<u8>> {
+        let info = &self.info;
+
+        if !(1..=LATEST_VERSION).contains(&self.version) {
+            return Err(invalid(format!("unsupported version {}", self.version)));
+        }
+
+        if self.version < 2 && info.uri.is_some() {
+            return Err(invalid("version 1 tracks cannot have a uri"));
+        }
+
+        if self.version < 3 && (info.artwork_url.is_some() || info.isrc.is_some()) {
+            return Err(invalid(format!(
+                "version {} tracks cannot have an artwork url or an isrc",
+                self.version
+            )));
+        }
+
+        let mut writer = Writer { bytes: vec![0; 4] };
+
+        if self.version > 1 {
+            writer.write_u8(self.version);
+        }
+
+        writer.write_utf(&info.title)?;
+        writer.write_utf(&info.author)?;
+        writer.write_u64(info.length)?;
+        writer.write_utf(&info.identifier)?;
+        writer.write_bool(info.is_stream);
+        if self.version >= 2 {
+            writer.write_nullable_utf(info.uri.as_deref())?;
+        }
+        if self.version >= 3 {
+            writer.write_nullable_utf(info.artwork_url.as_deref())?;
+            writer.write_nullable_utf(info.isrc.as_deref())?;
+        }
+        writer.write_utf(&info.source_name)?;
+        writer.bytes.extend_from_slice(&self.source_data);
+        writer.write_u64(info.position)?;
+
+        let size = writer.bytes.len() - 4;
+
+        if size > SIZE_MASK as usize {
+            return Err(invalid(format!("the message is too long ({} bytes)", size)));
+        }
+
+        let flags = if self.version > 1 { FLAG_VERSIONED } else { 0 };
+        let header = (flags << 30) | size as u32;
+        writer.bytes[..4].copy_from_slice(&header.to_be_bytes());
+
+        Ok(writer.bytes)
+    }
+}
+
+/// Decode a base64 encoded track into its info.
+///
+/// NOTE: Only the info stored in the track is returned. Some sources fill in more info when a
+/// node decodes the track, like the artwork url of YouTube tracks. `is_seekable` is not stored,
+/// so it's inferred as `!is_stream`.
+pub fn decode(encoded: &str) -> LavalinkResult<TrackInfo> {
+    EncodedTrack::decode(encoded).map(|track| track.info)
+}
+
+/// Encode track info into a base64 encoded track, using the latest version of the format.
+///
+/// NOTE: Tracks from sources that need extra data to be played, like HTTP or local files,
+/// must be encoded with `EncodedTrack` to keep their `source_data`.
+pub fn encode(info: &TrackInfo) -> LavalinkResult<String> {
+    EncodedTrack {
+        version: LATEST_VERSION,
+        info: info.clone(),
+        source_data: Vec::new(),
+    }
+    .encode()
+}
+
+impl TrackData {
+    /// Decode a base64 encoded track locally, without requesting it from a Lavalink node.
+    ///
+    /// Plugin info and user data are not part of the encoded track, so they are empty, and
+    /// `is_seekable` is inferred as `!is_stream`. See `encoded::decode()` for details.
+    pub fn from_encoded(encoded: impl Into<String>) -> LavalinkResult<Self> {
+        let encoded = encoded.into();
+        let info = decode(&encoded)?;
+
+        Ok(TrackData {
+            encoded,
+            info,
+            plugin_info: None,
+            user_data: None,
+        })
+    }
+}
+
+fn invalid(why: impl Into<String>) -> LavalinkError {
+    LavalinkError::InvalidEncodedTrack(why.into())
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.offset
+    }
+
+    fn take(&mut self, len: usize) -> LavalinkResult<&'a [u8]> {
+        if self.remaining() < len {
+            return Err(invalid(format!(
+                "unexpected end of the track at byte {}",
+                self.offset
+            )));
+        }
+
+        let bytes = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> LavalinkResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn read_bool(&mut self) -> LavalinkResult<bool> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    fn read_u16(&mut self) -> LavalinkResult<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn read_u32(&mut self) -> LavalinkResult<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Reads a Java `long`, which must not be negative.
+    fn read_u64(&mut self) -> LavalinkResult<u64> {
+        let bytes = self.take(8)?;
+        let value = i64::from_be_bytes(bytes.try_into().unwrap());
+
+        u64::try_from(value).map_err(|_| invalid(format!("negative number {}", value)))
+    }
+
+    /// Reads a string written with Java's `DataOutput.writeUTF`.
+    fn read_utf(&mut self) -> LavalinkResult<String> {
+        let len = self.read_u16()? as usize;
+        let bytes = self.take(len)?;
+
+        decode_modified_utf8(bytes)
+            .ok_or_else(|| invalid(format!("invalid string at byte {}", self.offset - len)))
+    }
+
+    fn read_nullable_utf(&mut self) -> LavalinkResult<Option<String>> {
+        if self.read_bool()? {
+            self.read_utf().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    fn write_u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    /// Writes a Java `long`, which cannot hold numbers above `i64::MAX`.
+    fn write_u64(&mut self, value: u64) -> LavalinkResult<()> {
+        let value =
+            i64::try_from(value).map_err(|_| invalid(format!("number {} is too large", value)))?;
+
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+
+        Ok(())
+    }
+
+    /// Writes a string like Java's `DataOutput.writeUTF`.
+    fn write_utf(&mut self, value: &str) -> LavalinkResult<()> {
+        let encoded = encode_modified_utf8(value);
+        let len = u16::try_from(encoded.len())
+            .map_err(|_| invalid(format!("string is too long ({} bytes)", encoded.len())))?;
+
+        self.bytes.extend_from_slice(&len.to_be_bytes());
+        self.bytes.extend_from_slice(&encoded);
+
+        Ok(())
+    }
+
+    fn write_nullable_utf(&mut self, value: Option<&str>) -> LavalinkResult<()> {
+        self.write_bool(value.is_some());
+
+        if let Some(value) = value {
+            self.write_utf(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Java's modified UTF-8 encodes UTF-16 code units, writes NUL as two bytes, and writes
+/// surrogate pairs as two separate three byte sequences.
+fn encode_modified_utf8(value: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(value.len());
+
+    for unit in value.encode_utf16() {
+        match unit {
+            0x0001..=0x007F => bytes.push(unit as u8),
+            0x0000 | 0x0080..=0x07FF => {
+                bytes.push(0xC0 | (unit >> 6) as u8);
+                bytes.push(0x80 | (unit & 0x3F) as u8);
+            }
+            _ => {
+                bytes.push(0xE0 | (unit >> 12) as u8);
+                bytes.push(0x80 | ((unit >> 6) & 0x3F) as u8);
+                bytes.push(0x80 | (unit & 0x3F) as u8);
+            }
+        }
+    }
+
+    bytes
+}
+
+fn decode_modified_utf8(bytes: &[u8]) -> Option<String> {
+    fn continuation(iter: &mut std::slice::Iter<u8>) -> Option<u16> {
+        let byte = *iter.next()?;
+        (byte & 0xC0 == 0x80).then_some((byte & 0x3F) as u16)
+    }
+
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut iter = bytes.iter();
+
+    while let Some(&byte) = iter.next() {
+        let unit = match byte {
+            0x01..=0x7F => byte as u16,
+            0xC0..=0xDF => ((byte & 0x1F) as u16) << 6 | continuation(&mut iter)?,
+            0xE0..=0xEF => {
+                ((byte & 0x0F) as u16) << 12
+                    | continuation(&mut iter)? << 6
+                    | continuation(&mut iter)?
+            }
+            _ => return None,
+        };
+
+        units.push(unit);
+    }
+
+    String::from_utf16(&units).ok()
+}
@@ -1,14 +1,17 @@
 use crate::client::LavalinkClient;
 use crate::error::LavalinkError;
+use crate::model::client::NodeState;
 use crate::model::{events, BoxFuture, Secret, UserId};
 
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use arc_swap::ArcSwap;
 use futures::stream::StreamExt;
-#[cfg(feature = "_tungstenite")]
 use http::HeaderMap;
+use hyper_util::rt::TokioIo;
 
 #[cfg(feature = "_tungstenite")]
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
@@ -22,10 +25,14 @@ use tokio_tungstenite::tungstenite::Message as TungsteniteMessage;
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 /// A builder for the node.
 ///
+/// The `connector` is only available from Rust, the Python `NodeBuilder` always uses the
+/// default connector.
+///
 /// # Example
 ///
 /// ```
-/// # use crate::model::UserId;
+/// # use lavalink_rs::model::UserId;
+/// # use lavalink_rs::node::NodeBuilder;
 /// let node_builder = NodeBuilder {
 ///     hostname: "localhost:2333".to_string(),
 ///     password: "youshallnotpass".to_string(),
@@ -50,6 +57,93 @@ pub struct NodeBuilder {
     pub user_id: UserId,
     /// The previous Session ID if resuming.
     pub session_id: Option<String>,
+    /// The timeout in seconds Lavalink will keep the session alive for after a disconnect.
+    ///
+    /// If set, resuming is enabled on every `ready` event, and players bound to this node get
+    /// restored after a reconnect. Otherwise, the players get moved to another node as soon as
+    /// the connection is lost if `LavalinkClient::set_failover()` is enabled.
+    pub resume_timeout: Option<u32>,
+    /// How to reconnect to the Lavalink server after the connection is lost.
+    pub reconnect_policy: ReconnectPolicy,
+    /// The timeout, retries and concurrency limit of the REST requests.
+    pub http_config: crate::http::HttpConfig,
+    /// Extra headers sent with the REST requests and the websocket handshake, like the
+    /// authentication of a reverse proxy.
+    ///
+    /// They replace the headers set by the library with the same name. Invalid names and values
+    /// are returned as errors when the node is added to a client.
+    pub extra_headers: Vec<(String, String)>,
+    /// The connector used to open the connections for the REST requests and the websocket, to
+    /// use a proxy, custom root certificates or client certificates.
+    ///
+    /// The connector must negotiate HTTP/1.1 over TLS for the websocket handshake to work.
+    /// None uses the default connector of the enabled TLS feature.
+    pub connector: Option<crate::http::Connector>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// How a node reconnects after losing the connection to the Lavalink server.
+///
+/// The delay before the first attempt is `initial_delay`, and it gets multiplied by `multiplier`
+/// after every failed attempt, up to `max_delay`.
+pub struct ReconnectPolicy {
+    /// The delay before the first reconnection attempt.
+    pub initial_delay: Duration,
+    /// The maximum delay between reconnection attempts.
+    pub max_delay: Duration,
+    /// The factor the delay gets multiplied by after every failed attempt.
+    pub multiplier: f64,
+    /// Whether to randomize every delay between half and the full delay, to avoid reconnecting at
+    /// the same time as other clients.
+    pub jitter: bool,
+    /// The amount of failed attempts before the node is marked as failed.
+    ///
+    /// None retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            multiplier: 2.0,
+            jitter: true,
+            max_attempts: None,
+        }
+    }
+}
+
+impl Hash for ReconnectPolicy {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.initial_delay.hash(state);
+        self.max_delay.hash(state);
+        self.multiplier.to_bits().hash(state);
+        self.jitter.hash(state);
+        self.max_attempts.hash(state);
+    }
+}
+
+impl ReconnectPolicy {
+    /// Get the delay before a reconnection attempt, starting from attempt 0.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let delay = Duration::try_from_secs_f64(
+            self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent),
+        )
+        .unwrap_or(self.max_delay)
+        .min(self.max_delay);
+
+        if !self.jitter {
+            return delay;
+        }
+
+        let half = delay / 2;
+        let max_jitter = u64::try_from(half.as_nanos()).unwrap_or(u64::MAX);
+
+        half.saturating_add(Duration::from_nanos(fastrand::u64(..=max_jitter)))
+    }
 }
 
 #[derive(Debug)]
@@ -61,10 +155,17 @@ pub struct Node {
     pub http: crate::http::Http,
     pub events: events::Events,
     pub is_running: AtomicBool,
+    pub state: ArcSwap<NodeState>,
+    pub resume_timeout: Option<u32>,
+    pub reconnect_policy: ReconnectPolicy,
     pub(crate) password: Secret,
+    pub(crate) resumable: AtomicBool,
+    pub(crate) shutdown: tokio::sync::watch::Sender<bool>,
     pub user_id: UserId,
     pub cpu: ArcSwap<crate::model::events::Cpu>,
     pub memory: ArcSwap<crate::model::events::Memory>,
+    pub(crate) extra_headers: HeaderMap,
+    pub(crate) connector: Option<crate::http::Connector>,
 }
 
 #[derive(Copy, Clone)]
@@ -75,32 +176,227 @@ impl<'a> EventDispatcher<'a> {
     pub(crate) async fn dispatch<T, F>(self, event: T, handler: F)
     where
         F: Fn(&events::Events) -> Option<fn(LavalinkClient, String, &T) -> BoxFuture<()>>,
+        T: events::HandlerEvent,
     {
         let EventDispatcher(self_node, lavalink_client) = self;
-        let session_id = self_node.session_id.load_full();
-        let targets = [&self_node.events, &lavalink_client.events].into_iter();
 
-        for handler in targets.filter_map(handler) {
-            handler(lavalink_client.clone(), (*session_id).clone(), &event).await;
+        for events in [&self_node.events, &lavalink_client.events] {
+            self.dispatch_to(events, &event, &handler).await;
         }
     }
 
-    #[cfg(not(feature = "python"))]
-    pub(crate) async fn parse_and_dispatch<T: serde::de::DeserializeOwned, F>(
-        self,
-        event: serde_json::Value,
-        handler: F,
-    ) where
+    async fn dispatch_to<T, F>(self, events: &events::Events, event: &T, handler: &F)
+    where
         F: Fn(&events::Events) -> Option<fn(LavalinkClient, String, &T) -> BoxFuture<()>>,
-        T: serde::de::DeserializeOwned,
+        T: events::HandlerEvent,
     {
-        trace!("{:?}", event);
-        let event = serde_json::from_value(event).unwrap();
-        self.dispatch(event, handler).await
+        let EventDispatcher(self_node, lavalink_client) = self;
+        let session_id = self_node.session_id.load_full();
+
+        if let Some(handler) = handler(events) {
+            handler(lavalink_client.clone(), (*session_id).clone(), event).await;
+        }
+
+        if let Some(events::SharedEventHandler(handler)) = &events.handler {
+            event
+                .call_handler(&**handler, lavalink_client.clone(), (*session_id).clone())
+                .await;
+        }
+    }
+
+    /// Dispatch a plugin event to the typed handlers registered for its type, or to the
+    /// `plugin_event` handlers otherwise.
+    pub(crate) async fn dispatch_plugin_event(self, event: events::PluginEvent) {
+        let EventDispatcher(self_node, lavalink_client) = self;
+        let session_id = self_node.session_id.load_full();
+
+        for events in [&self_node.events, &lavalink_client.events] {
+            let Some(handler) = events.plugin_events.get(&event.event_type) else {
+                self.dispatch_to(events, &event, &|e| e.plugin_event).await;
+                continue;
+            };
+
+            match handler(lavalink_client.clone(), (*session_id).clone(), &event.raw) {
+                Ok(future) => future.await,
+                Err(why) => {
+                    error!(
+                        "Error decoding plugin event {} from node {}: {}",
+                        event.event_type, self_node.id, why
+                    );
+
+                    let decode_error = events::DecodeError {
+                        raw: event.raw.clone(),
+                        error: Arc::new(why),
+                    };
+
+                    self.dispatch_to(events, &decode_error, &|e| e.decode_error)
+                        .await;
+                }
+            }
+        }
     }
 }
 
 impl Node {
+    /// Get the current connection state of the node.
+    pub fn state(&self) -> NodeState {
+        **self.state.load()
+    }
+
+    pub(crate) async fn set_state(&self, lavalink_client: &LavalinkClient, state: NodeState) {
+        let old_state = *self.state.swap(Arc::new(state));
+        self.is_running
+            .store(state == NodeState::Connected, Ordering::SeqCst);
+
+        if old_state == state {
+            return;
+        }
+
+        debug!(
+            "Node {} changed state from {:?} to {:?}",
+            self.id, old_state, state
+        );
+
+        let event = events::NodeStateUpdate {
+            node_id: self.id,
+            old_state,
+            new_state: state,
+        };
+
+        #[cfg(feature = "python")]
+        {
+            let session_id = self.session_id.load_full();
+
+            if let Some(handler) = &self.events.event_handler {
+                handler
+                    .event_node_state_update(
+                        lavalink_client.clone(),
+                        (*session_id).clone(),
+                        event.clone(),
+                    )
+                    .await;
+            }
+            if let Some(handler) = &lavalink_client.events.event_handler {
+                handler
+                    .event_node_state_update(
+                        lavalink_client.clone(),
+                        (*session_id).clone(),
+                        event.clone(),
+                    )
+                    .await;
+            }
+        }
+
+        EventDispatcher(self, lavalink_client)
+            .dispatch(event, |e| e.node_state_update)
+            .await;
+    }
+
+    /// Reconnect to the Lavalink server following the reconnect policy, until it succeeds or the
+    /// maximum amount of attempts is reached.
+    pub(crate) fn reconnect(&self, lavalink_client: LavalinkClient) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let mut attempt = 0;
+
+            loop {
+                if self
+                    .reconnect_policy
+                    .max_attempts
+                    .is_some_and(|max_attempts| attempt >= max_attempts)
+                {
+                    error!(
+                        "Failed to reconnect to node {} after {} attempts.",
+                        self.id, attempt
+                    );
+                    self.set_state(&lavalink_client, NodeState::Failed).await;
+
+                    if lavalink_client.failover_enabled() {
+                        lavalink_client.failover_players(self).await;
+                    }
+                    return;
+                }
+
+                let delay = self.reconnect_policy.delay(attempt);
+                debug!("Reconnecting to node {} in {:?}", self.id, delay);
+
+                attempt += 1;
+
+                let event = events::NodeReconnecting {
+                    node_id: self.id,
+                    attempt,
+                    delay_ms: delay.as_millis() as u64,
+                };
+
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self.session_id.load_full();
+
+                    if let Some(handler) = &self.events.event_handler {
+                        handler
+                            .event_node_reconnecting(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_node_reconnecting(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
+                }
+
+                EventDispatcher(self, &lavalink_client)
+                    .dispatch(event, |e| e.node_reconnecting)
+                    .await;
+
+                tokio::time::sleep(delay).await;
+
+                if *self.shutdown.borrow() {
+                    return;
+                }
+
+                match self.connect(lavalink_client.clone()).await {
+                    Ok(()) => return,
+                    Err(why) => error!("Failed to connect to the lavalink websocket: {}", why),
+                }
+            }
+        })
+    }
+
+    /// Enable resuming for the current session, and restore the players bound to this node.
+    async fn enable_resuming(&self, lavalink_client: &LavalinkClient, ready_event: &events::Ready) {
+        match self
+            .http
+            .set_resuming_state(
+                &ready_event.session_id,
+                &crate::model::http::ResumingState {
+                    resuming: Some(true),
+                    timeout: self.resume_timeout,
+                },
+            )
+            .await
+        {
+            Ok(_) => self.resumable.store(true, Ordering::SeqCst),
+            Err(why) => error!(
+                "Error setting the resuming state for node {}: {}",
+                self.id, why
+            ),
+        }
+
+        if let Err(why) = lavalink_client
+            .restore_players(self, ready_event.resumed)
+            .await
+        {
+            error!("Error restoring the players for node {}: {}", self.id, why);
+        }
+    }
+
     /// Create a connection to the Lavalink server.
     #[cfg(feature = "_tungstenite")]
     pub async fn connect(&self, lavalink_client: LavalinkClient) -> Result<(), LavalinkError> {
@@ -116,46 +412,73 @@ impl Node {
 
         let mut url = self.websocket_address.clone().into_client_request()?;
 
-        {
-            let ref_headers = url.headers_mut();
-
-            let mut headers = HeaderMap::new();
-            headers.insert("Authorization", self.password.0.parse()?);
-            headers.insert("User-Id", self.user_id.0.to_string().parse()?);
-            headers.insert("Session-Id", self.session_id.to_string().parse()?);
-            headers.insert(
-                "Client-Name",
-                format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"),)
-                    .to_string()
-                    .parse()?,
-            );
-
-            ref_headers.extend(headers.clone());
-        }
+        url.headers_mut().extend(self.websocket_headers()?);
 
-        let (ws_stream, _) = tokio_tungstenite::connect_async_with_config(
-            url,
-            Some(
-                WebSocketConfig::default()
-                    .max_message_size(None)
-                    .max_frame_size(None),
-            ),
-            false,
-        )
-        .await?;
+        self.set_state(&lavalink_client, NodeState::Connecting)
+            .await;
 
-        info!("Connected to {}", self.websocket_address);
+        let config = WebSocketConfig::default()
+            .max_message_size(None)
+            .max_frame_size(None);
 
-        let (_write, mut read) = ws_stream.split();
+        let read = match &self.connector {
+            Some(connector) => match self.connect_stream(connector).await {
+                Ok(stream) => {
+                    tokio_tungstenite::client_async_with_config(url, stream, Some(config))
+                        .await
+                        .map(|(ws_stream, _)| ws_stream.split().1.boxed())
+                        .map_err(LavalinkError::from)
+                }
+                Err(why) => Err(why),
+            },
+            None => tokio_tungstenite::connect_async_with_config(url, Some(config), false)
+                .await
+                .map(|(ws_stream, _)| ws_stream.split().1.boxed())
+                .map_err(LavalinkError::from),
+        };
+
+        let read = match read {
+            Ok(x) => x,
+            Err(why) => {
+                self.set_state(&lavalink_client, NodeState::Disconnected)
+                    .await;
+                return Err(why);
+            }
+        };
 
-        self.is_running.store(true, Ordering::SeqCst);
+        info!("Connected to {}", self.websocket_address);
+
+        if self.resume_timeout.is_some() && self.resumable.load(Ordering::SeqCst) {
+            self.set_state(&lavalink_client, NodeState::Resuming).await;
+        }
 
         let self_node_id = self.id;
+        let mut shutdown = self.shutdown.subscribe();
+        let mut read = read.take_until(Box::pin(async move {
+            let _ = shutdown.wait_for(|x| *x).await;
+        }));
 
         tokio::spawn(async move {
-            while let Some(Ok(resp)) = read.next().await {
+            let mut close_code = None;
+            let mut close_reason = String::new();
+
+            loop {
+                let resp = match read.next().await {
+                    Some(Ok(resp)) => resp,
+                    Some(Err(why)) => {
+                        close_reason = why.to_string();
+                        break;
+                    }
+                    None => break,
+                };
+
                 let x = match resp {
                     TungsteniteMessage::Text(x) => x,
+                    TungsteniteMessage::Close(Some(frame)) => {
+                        close_code = Some(frame.code.into());
+                        close_reason = frame.reason.to_string();
+                        continue;
+                    }
                     _ => continue,
                 };
 
@@ -171,9 +494,7 @@ impl Node {
                 });
             }
 
-            let self_node = lavalink_client.nodes.get(self_node_id).unwrap();
-            self_node.is_running.store(false, Ordering::SeqCst);
-            error!("Connection Closed.");
+            Node::handle_disconnect(lavalink_client, self_node_id, close_code, close_reason).await;
         });
 
         Ok(())
@@ -184,38 +505,72 @@ impl Node {
     pub async fn connect(&self, lavalink_client: LavalinkClient) -> Result<(), LavalinkError> {
         let uri = <::http::Uri as std::str::FromStr>::from_str(&self.websocket_address)?;
 
-        let (client, _) = tokio_websockets::ClientBuilder::from_uri(uri)
-            .add_header(
-                "authorization".try_into().unwrap(),
-                self.password.0.parse()?,
-            )?
-            .add_header(
-                "user-id".try_into().unwrap(),
-                self.user_id.0.to_string().parse()?,
-            )?
-            .add_header(
-                "session-id".try_into().unwrap(),
-                self.session_id.to_string().parse()?,
-            )?
-            .add_header(
-                "client-name".try_into().unwrap(),
-                format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"),)
-                    .to_string()
-                    .parse()?,
-            )?
-            .connect()
-            .await?;
+        let mut client_builder = tokio_websockets::ClientBuilder::from_uri(uri);
 
-        info!("Connected to {}", self.websocket_address);
+        for (name, value) in &self.websocket_headers()? {
+            client_builder = client_builder.add_header(name.clone(), value.clone())?;
+        }
 
-        let (_write, mut read) = client.split();
+        self.set_state(&lavalink_client, NodeState::Connecting)
+            .await;
+
+        let read = match &self.connector {
+            Some(connector) => match self.connect_stream(connector).await {
+                Ok(stream) => client_builder
+                    .connect_on(stream)
+                    .await
+                    .map(|(client, _)| client.split().1.boxed())
+                    .map_err(LavalinkError::from),
+                Err(why) => Err(why),
+            },
+            None => client_builder
+                .connect()
+                .await
+                .map(|(client, _)| client.split().1.boxed())
+                .map_err(LavalinkError::from),
+        };
+
+        let read = match read {
+            Ok(x) => x,
+            Err(why) => {
+                self.set_state(&lavalink_client, NodeState::Disconnected)
+                    .await;
+                return Err(why);
+            }
+        };
 
-        self.is_running.store(true, Ordering::SeqCst);
+        info!("Connected to {}", self.websocket_address);
+
+        if self.resume_timeout.is_some() && self.resumable.load(Ordering::SeqCst) {
+            self.set_state(&lavalink_client, NodeState::Resuming).await;
+        }
 
         let self_node_id = self.id;
+        let mut shutdown = self.shutdown.subscribe();
+        let mut read = read.take_until(Box::pin(async move {
+            let _ = shutdown.wait_for(|x| *x).await;
+        }));
 
         tokio::spawn(async move {
-            while let Some(Ok(resp)) = read.next().await {
+            let mut close_code = None;
+            let mut close_reason = String::new();
+
+            loop {
+                let resp = match read.next().await {
+                    Some(Ok(resp)) => resp,
+                    Some(Err(why)) => {
+                        close_reason = why.to_string();
+                        break;
+                    }
+                    None => break,
+                };
+
+                if let Some((code, reason)) = resp.as_close() {
+                    close_code = Some(code.into());
+                    close_reason = reason.to_string();
+                    continue;
+                }
+
                 let x = match resp.as_text() {
                     Some(x) => x,
                     _ => continue,
@@ -233,31 +588,211 @@ impl Node {
                 });
             }
 
-            let self_node = lavalink_client.nodes.get(self_node_id).unwrap();
-            self_node.is_running.store(false, Ordering::SeqCst);
-            error!("Connection Closed.");
+            Node::handle_disconnect(lavalink_client, self_node_id, close_code, close_reason).await;
         });
 
         Ok(())
     }
 
+    /// The headers sent with the websocket handshake.
+    fn websocket_headers(&self) -> Result<HeaderMap, LavalinkError> {
+        let mut headers = HeaderMap::new();
+        headers.insert("Authorization", self.password.0.parse()?);
+        headers.insert("User-Id", self.user_id.0.to_string().parse()?);
+        headers.insert("Session-Id", self.session_id.to_string().parse()?);
+        headers.insert(
+            "Client-Name",
+            format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"),)
+                .to_string()
+                .parse()?,
+        );
+
+        headers.extend(self.extra_headers.clone());
+
+        Ok(headers)
+    }
+
+    /// Open the connection for the websocket with a custom connector, which handles TLS.
+    async fn connect_stream(
+        &self,
+        connector: &crate::http::Connector,
+    ) -> Result<TokioIo<crate::http::ConnectorIo>, LavalinkError> {
+        let address = match self.websocket_address.strip_prefix("wss://") {
+            Some(address) => format!("https://{}", address),
+            None => self.websocket_address.replacen("ws://", "http://", 1),
+        };
+
+        let stream = connector
+            .connect(address.parse()?)
+            .await
+            .map_err(std::io::Error::other)?;
+
+        Ok(TokioIo::new(stream))
+    }
+
+    async fn handle_disconnect(
+        lavalink_client: LavalinkClient,
+        self_node_id: usize,
+        close_code: Option<u16>,
+        close_reason: String,
+    ) {
+        let Some(self_node) = lavalink_client.get_node_by_id(self_node_id) else {
+            info!("Connection to removed node {} closed.", self_node_id);
+            return;
+        };
+
+        self_node
+            .set_state(&lavalink_client, NodeState::Disconnected)
+            .await;
+        error!(
+            "Connection to node {} closed: {:?} {}",
+            self_node_id, close_code, close_reason
+        );
+
+        let event = events::NodeDisconnected {
+            node_id: self_node_id,
+            code: close_code,
+            reason: close_reason,
+        };
+
+        #[cfg(feature = "python")]
+        {
+            let session_id = self_node.session_id.load_full();
+
+            if let Some(handler) = &self_node.events.event_handler {
+                handler
+                    .event_node_disconnected(
+                        lavalink_client.clone(),
+                        (*session_id).clone(),
+                        event.clone(),
+                    )
+                    .await;
+            }
+            if let Some(handler) = &lavalink_client.events.event_handler {
+                handler
+                    .event_node_disconnected(
+                        lavalink_client.clone(),
+                        (*session_id).clone(),
+                        event.clone(),
+                    )
+                    .await;
+            }
+        }
+
+        EventDispatcher(&self_node, &lavalink_client)
+            .dispatch(event, |e| e.node_disconnected)
+            .await;
+
+        if self_node.resume_timeout.is_none() && lavalink_client.failover_enabled() {
+            lavalink_client.failover_players(&self_node).await;
+        }
+
+        self_node.reconnect(lavalink_client).await;
+    }
+
     async fn handle_event(
         lavalink_client: LavalinkClient,
         self_node_id: usize,
         base_event: serde_json::Value,
     ) {
-        let base_event_clone = base_event.clone();
-        let self_node = lavalink_client.nodes.get(self_node_id).unwrap();
+        let Some(self_node) = lavalink_client.get_node_by_id(self_node_id) else {
+            return;
+        };
+        let self_node = &*self_node;
         let ed = EventDispatcher(self_node, &lavalink_client);
 
-        match base_event.get("op").unwrap().as_str().unwrap() {
-            "ready" => {
-                let ready_event: events::Ready = serde_json::from_value(base_event).unwrap();
+        trace!("{:?}", base_event);
+
+        let event = events::LavalinkEvent::decode(&base_event);
 
+        // Streams get the event first, so slow handlers don't delay them.
+        if let Ok(event) = &event {
+            if lavalink_client.event_tx.receiver_count() > 0 {
+                let _ = lavalink_client.event_tx.send(events::NodeEvent {
+                    node_id: self_node_id,
+                    event: event.clone(),
+                });
+            }
+        }
+
+        match event {
+            Ok(events::LavalinkEvent::Ready(ready_event)) => {
                 self_node
                     .session_id
                     .swap(Arc::new(ready_event.session_id.to_string()));
 
+                self_node
+                    .set_state(&lavalink_client, NodeState::Connected)
+                    .await;
+
+                if ready_event.resumed {
+                    let event = events::NodeResumed {
+                        node_id: self_node_id,
+                    };
+
+                    #[cfg(feature = "python")]
+                    {
+                        let session_id = self_node.session_id.load_full();
+
+                        if let Some(handler) = &self_node.events.event_handler {
+                            handler
+                                .event_node_resumed(
+                                    lavalink_client.clone(),
+                                    (*session_id).clone(),
+                                    event.clone(),
+                                )
+                                .await;
+                        }
+                        if let Some(handler) = &lavalink_client.events.event_handler {
+                            handler
+                                .event_node_resumed(
+                                    lavalink_client.clone(),
+                                    (*session_id).clone(),
+                                    event.clone(),
+                                )
+                                .await;
+                        }
+                    }
+
+                    ed.dispatch(event, |e| e.node_resumed).await;
+                } else {
+                    let event = events::NodeConnected {
+                        node_id: self_node_id,
+                    };
+
+                    #[cfg(feature = "python")]
+                    {
+                        let session_id = self_node.session_id.load_full();
+
+                        if let Some(handler) = &self_node.events.event_handler {
+                            handler
+                                .event_node_connected(
+                                    lavalink_client.clone(),
+                                    (*session_id).clone(),
+                                    event.clone(),
+                                )
+                                .await;
+                        }
+                        if let Some(handler) = &lavalink_client.events.event_handler {
+                            handler
+                                .event_node_connected(
+                                    lavalink_client.clone(),
+                                    (*session_id).clone(),
+                                    event.clone(),
+                                )
+                                .await;
+                        }
+                    }
+
+                    ed.dispatch(event, |e| e.node_connected).await;
+                }
+
+                if self_node.resume_timeout.is_some() {
+                    self_node
+                        .enable_resuming(&lavalink_client, &ready_event)
+                        .await;
+                }
+
                 #[cfg(feature = "python")]
                 {
                     let session_id = self_node.session_id.load_full();
@@ -284,10 +819,7 @@ impl Node {
 
                 ed.dispatch(ready_event, |e| e.ready).await;
             }
-            "playerUpdate" => {
-                let player_update_event: events::PlayerUpdate =
-                    serde_json::from_value(base_event).unwrap();
-
+            Ok(events::LavalinkEvent::PlayerUpdate(player_update_event)) => {
                 if let Some(player) =
                     lavalink_client.get_player_context(player_update_event.guild_id)
                 {
@@ -325,15 +857,14 @@ impl Node {
 
                 ed.dispatch(player_update_event, |e| e.player_update).await;
             }
-            "stats" => {
+            Ok(events::LavalinkEvent::Stats(event)) => {
+                self_node.cpu.store(Arc::new(event.cpu.clone()));
+                self_node.memory.store(Arc::new(event.memory.clone()));
+
                 #[cfg(feature = "python")]
                 {
-                    let event: events::Stats = serde_json::from_value(base_event).unwrap();
                     let session_id = self_node.session_id.load_full();
 
-                    self_node.cpu.store(Arc::new(event.cpu.clone()));
-                    self_node.memory.store(Arc::new(event.memory.clone()));
-
                     if let Some(handler) = &self_node.events.event_handler {
                         handler
                             .event_stats(
@@ -352,197 +883,211 @@ impl Node {
                             )
                             .await;
                     }
-
-                    ed.dispatch(event, |e| e.stats).await;
                 }
-                #[cfg(not(feature = "python"))]
-                ed.parse_and_dispatch(base_event, |e| e.stats).await;
+
+                ed.dispatch(event, |e| e.stats).await;
             }
-            "event" => match base_event.get("type").unwrap().as_str().unwrap() {
-                "TrackStartEvent" => {
-                    let track_event: events::TrackStart =
-                        serde_json::from_value(base_event).unwrap();
-
-                    if let Some(player) = lavalink_client.get_player_context(track_event.guild_id) {
-                        if let Err(why) = player.update_track(track_event.track.clone().into()) {
-                            error!(
-                                "Error sending update track message for player {}: {}",
-                                track_event.guild_id.0, why
-                            );
-                        }
+            Ok(events::LavalinkEvent::TrackStart(track_event)) => {
+                if let Some(player) = lavalink_client.get_player_context(track_event.guild_id) {
+                    if let Err(why) = player.update_track(track_event.track.clone().into()) {
+                        error!(
+                            "Error sending update track message for player {}: {}",
+                            track_event.guild_id.0, why
+                        );
                     }
+                }
 
-                    #[cfg(feature = "python")]
-                    {
-                        let session_id = self_node.session_id.load_full();
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
 
-                        if let Some(handler) = &self_node.events.event_handler {
-                            handler
-                                .event_track_start(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    track_event.clone(),
-                                )
-                                .await;
-                        }
-                        if let Some(handler) = &lavalink_client.events.event_handler {
-                            handler
-                                .event_track_start(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    track_event.clone(),
-                                )
-                                .await;
-                        }
+                    if let Some(handler) = &self_node.events.event_handler {
+                        handler
+                            .event_track_start(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                track_event.clone(),
+                            )
+                            .await;
+                    }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_track_start(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                track_event.clone(),
+                            )
+                            .await;
                     }
-
-                    ed.dispatch(track_event, |e| e.track_start).await;
                 }
-                "TrackEndEvent" => {
-                    let track_event: events::TrackEnd = serde_json::from_value(base_event).unwrap();
-
-                    if let Some(player) = lavalink_client.get_player_context(track_event.guild_id) {
-                        if let Err(why) = player.finish(track_event.reason.clone().into()) {
-                            error!(
-                                "Error sending finish message for player {}: {}",
-                                track_event.guild_id.0, why
-                            );
-                        }
 
-                        if let Err(why) = player.update_track(None) {
-                            error!(
-                                "Error sending update track message for player {}: {}",
-                                track_event.guild_id.0, why
-                            );
-                        }
+                ed.dispatch(track_event, |e| e.track_start).await;
+            }
+            Ok(events::LavalinkEvent::TrackEnd(track_event)) => {
+                if let Some(player) = lavalink_client.get_player_context(track_event.guild_id) {
+                    if let Err(why) = player.track_ended(track_event.reason.clone()) {
+                        error!(
+                            "Error sending finish message for player {}: {}",
+                            track_event.guild_id.0, why
+                        );
                     }
 
-                    #[cfg(feature = "python")]
-                    {
-                        let session_id = self_node.session_id.load_full();
+                    if let Err(why) = player.update_track(None) {
+                        error!(
+                            "Error sending update track message for player {}: {}",
+                            track_event.guild_id.0, why
+                        );
+                    }
+                }
 
-                        if let Some(handler) = &self_node.events.event_handler {
-                            handler
-                                .event_track_end(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    track_event.clone(),
-                                )
-                                .await;
-                        }
-                        if let Some(handler) = &lavalink_client.events.event_handler {
-                            handler
-                                .event_track_end(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    track_event.clone(),
-                                )
-                                .await;
-                        }
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
+
+                    if let Some(handler) = &self_node.events.event_handler {
+                        handler
+                            .event_track_end(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                track_event.clone(),
+                            )
+                            .await;
                     }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_track_end(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                track_event.clone(),
+                            )
+                            .await;
+                    }
+                }
 
-                    ed.dispatch(track_event, |e| e.track_end).await;
+                ed.dispatch(track_event, |e| e.track_end).await;
+            }
+            Ok(events::LavalinkEvent::TrackException(event)) => {
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
+
+                    if let Some(handler) = &self_node.events.event_handler {
+                        handler
+                            .event_track_exception(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_track_exception(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
                 }
-                "TrackExceptionEvent" => {
-                    #[cfg(feature = "python")]
-                    {
-                        let event: events::TrackException =
-                            serde_json::from_value(base_event).unwrap();
-                        let session_id = self_node.session_id.load_full();
 
-                        if let Some(handler) = &self_node.events.event_handler {
-                            handler
-                                .event_track_exception(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    event.clone(),
-                                )
-                                .await;
-                        }
-                        if let Some(handler) = &lavalink_client.events.event_handler {
-                            handler
-                                .event_track_exception(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    event.clone(),
-                                )
-                                .await;
-                        }
+                ed.dispatch(event, |e| e.track_exception).await;
+            }
+            Ok(events::LavalinkEvent::TrackStuck(event)) => {
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
 
-                        ed.dispatch(event, |e| e.track_exception).await;
+                    if let Some(handler) = &self_node.events.event_handler {
+                        handler
+                            .event_track_stuck(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_track_stuck(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
                     }
-                    #[cfg(not(feature = "python"))]
-                    ed.parse_and_dispatch(base_event, |e| e.track_exception)
-                        .await;
                 }
-                "TrackStuckEvent" => {
-                    #[cfg(feature = "python")]
-                    {
-                        let event: events::TrackStuck = serde_json::from_value(base_event).unwrap();
-                        let session_id = self_node.session_id.load_full();
 
-                        if let Some(handler) = &self_node.events.event_handler {
-                            handler
-                                .event_track_stuck(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    event.clone(),
-                                )
-                                .await;
-                        }
-                        if let Some(handler) = &lavalink_client.events.event_handler {
-                            handler
-                                .event_track_stuck(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    event.clone(),
-                                )
-                                .await;
-                        }
+                ed.dispatch(event, |e| e.track_stuck).await;
+            }
+            Ok(events::LavalinkEvent::WebSocketClosed(event)) => {
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
 
-                        ed.dispatch(event, |e| e.track_stuck).await;
+                    if let Some(handler) = &self_node.events.event_handler {
+                        handler
+                            .event_websocket_closed(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
+                    }
+                    if let Some(handler) = &lavalink_client.events.event_handler {
+                        handler
+                            .event_websocket_closed(
+                                lavalink_client.clone(),
+                                (*session_id).clone(),
+                                event.clone(),
+                            )
+                            .await;
                     }
-                    #[cfg(not(feature = "python"))]
-                    ed.parse_and_dispatch(base_event, |e| e.track_stuck).await;
                 }
-                "WebSocketClosedEvent" => {
-                    #[cfg(feature = "python")]
-                    {
-                        let event: events::WebSocketClosed =
-                            serde_json::from_value(base_event).unwrap();
-                        let session_id = self_node.session_id.load_full();
 
-                        if let Some(handler) = &self_node.events.event_handler {
-                            handler
-                                .event_websocket_closed(
-                                    lavalink_client.clone(),
-                                    (*session_id).clone(),
-                                    event.clone(),
-                                )
-                                .await;
+                ed.dispatch(event, |e| e.websocket_closed).await;
+            }
+            Ok(events::LavalinkEvent::Unknown(raw)) => {
+                let event = events::PluginEvent::from_raw(raw);
+
+                #[cfg(feature = "python")]
+                {
+                    let session_id = self_node.session_id.load_full();
+
+                    // Like `plugin_event`, only called when no typed handler handles the event.
+                    for events in [&self_node.events, &lavalink_client.events] {
+                        if events.plugin_events.get(&event.event_type).is_some() {
+                            continue;
                         }
-                        if let Some(handler) = &lavalink_client.events.event_handler {
+
+                        if let Some(handler) = &events.event_handler {
                             handler
-                                .event_websocket_closed(
+                                .event_plugin_event(
                                     lavalink_client.clone(),
                                     (*session_id).clone(),
                                     event.clone(),
                                 )
                                 .await;
                         }
-
-                        ed.dispatch(event, |e| e.websocket_closed).await;
                     }
-                    #[cfg(not(feature = "python"))]
-                    ed.parse_and_dispatch(base_event, |e| e.websocket_closed)
-                        .await;
                 }
-                _ => (),
-            },
 
-            _ => (),
+                ed.dispatch_plugin_event(event).await;
+            }
+            Err(why) => {
+                error!("Error decoding event from node {}: {}", self_node_id, why);
+
+                if let LavalinkError::SerdeErrorJson(error) = why {
+                    let event = events::DecodeError {
+                        raw: base_event.clone(),
+                        error: Arc::new(error),
+                    };
+
+                    ed.dispatch(event, |e| e.decode_error).await;
+                }
+            }
         }
 
-        ed.dispatch(base_event_clone, |e| e.raw).await;
+        ed.dispatch(base_event, |e| e.raw).await;
     }
 }
@@ -9,6 +9,8 @@ use std::task::Context;
 use std::task::Poll;
 
 use ::http::Method;
+use futures::stream::{Stream, StreamExt};
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::UnboundedSender;
 
 #[derive(Clone)]
@@ -18,6 +20,7 @@ pub struct PlayerContext {
     pub guild_id: GuildId,
     pub client: LavalinkClient,
     pub(crate) tx: UnboundedSender<super::PlayerMessage>,
+    pub(crate) queue_event_tx: broadcast::Sender<super::QueueEvent>,
     pub(crate) user_data: std::sync::Arc<dyn std::any::Any + Send + Sync>,
 }
 
@@ -26,6 +29,7 @@ pub struct PlayerContext {
 /// A reference to the player queue
 pub struct QueueRef {
     pub(crate) tx: UnboundedSender<super::PlayerMessage>,
+    pub(crate) queue_event_tx: broadcast::Sender<super::QueueEvent>,
     pub(crate) stream:
         std::sync::Arc<std::sync::Mutex<dyn futures::Stream<Item = super::TrackInQueue> + Send>>,
 }
@@ -49,8 +53,104 @@ impl PlayerContext {
     ///
     /// - `should_continue`: if the next track in the queue should play.
     pub fn finish(&self, should_continue: bool) -> LavalinkResult<()> {
+        let reason = if should_continue {
+            events::TrackEndReason::Finished
+        } else {
+            events::TrackEndReason::Stopped
+        };
+
+        self.track_ended(reason)
+    }
+
+    pub(crate) fn track_ended(&self, reason: events::TrackEndReason) -> LavalinkResult<()> {
+        self.tx.send(super::PlayerMessage::TrackFinished(reason))?;
+        Ok(())
+    }
+
+    /// Set how the queue is looped when tracks finish.
+    pub fn set_loop_mode(&self, loop_mode: super::LoopMode) -> LavalinkResult<()> {
+        self.tx.send(super::PlayerMessage::SetLoopMode(loop_mode))?;
+        Ok(())
+    }
+
+    /// Set the limits on the tracks that can be added to the queue.
+    ///
+    /// The queue operations that would break the policy return `LavalinkError::QueueRejected`
+    /// without modifying the queue.
+    pub fn set_queue_policy(&self, queue_policy: super::QueuePolicy) -> LavalinkResult<()> {
+        self.tx
+            .send(super::PlayerMessage::SetQueuePolicy(queue_policy))?;
+        Ok(())
+    }
+
+    /// Get the limits on the tracks that can be added to the queue.
+    pub async fn get_queue_policy(&self) -> LavalinkResult<super::QueuePolicy> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx.send(super::PlayerMessage::GetQueuePolicy(tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Fade between the tracks of the queue, or disable it with None.
+    pub fn set_crossfade(&self, crossfade: Option<super::Crossfade>) -> LavalinkResult<()> {
         self.tx
-            .send(super::PlayerMessage::TrackFinished(should_continue))?;
+            .send(super::PlayerMessage::SetCrossfade(crossfade))?;
+        Ok(())
+    }
+
+    /// Get how the queue is looped when tracks finish.
+    pub async fn get_loop_mode(&self) -> LavalinkResult<super::LoopMode> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx.send(super::PlayerMessage::GetLoopMode(tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Play the last track in the history again, and add the current track back to the start of
+    /// the queue.
+    ///
+    /// Returns the track that is played, or None if the history is empty.
+    pub async fn previous(&self) -> LavalinkResult<Option<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx.send(super::PlayerMessage::PlayPrevious(tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Set the maximum amount of played tracks kept in the history.
+    ///
+    /// Defaults to [`DEFAULT_HISTORY_SIZE`](super::DEFAULT_HISTORY_SIZE).
+    pub fn set_history_size(&self, history_size: usize) -> LavalinkResult<()> {
+        self.tx
+            .send(super::PlayerMessage::SetHistorySize(history_size))?;
+        Ok(())
+    }
+
+    /// Set a handler that gets called when the queue is empty and the player would stop, to
+    /// supply more tracks, like an autoplay or radio feature.
+    ///
+    /// The handler receives the last track that was played. If it returns no tracks, the player
+    /// stops.
+    pub fn set_queue_empty_handler<F, Fut>(&self, handler: F) -> LavalinkResult<()>
+    where
+        F: Fn(PlayerContext, Option<super::TrackInQueue>) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Vec<super::TrackInQueue>> + Send + 'static,
+    {
+        let handler: super::QueueEmptyHandler =
+            std::sync::Arc::new(move |player, last_track| Box::pin(handler(player, last_track)));
+
+        self.tx
+            .send(super::PlayerMessage::SetQueueEmptyHandler(Some(handler)))?;
+        Ok(())
+    }
+
+    /// Remove the handler set with `set_queue_empty_handler`.
+    pub fn clear_queue_empty_handler(&self) -> LavalinkResult<()> {
+        self.tx
+            .send(super::PlayerMessage::SetQueueEmptyHandler(None))?;
         Ok(())
     }
 
@@ -73,9 +173,19 @@ impl PlayerContext {
     }
 
     /// Add a track to the end of the queue.
-    pub fn queue(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
-        let q = self.get_queue();
-        q.send(super::QueueMessage::PushToBack(track.into()))
+    pub async fn queue(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
+        self.get_queue().push_to_back(track).await
+    }
+
+    /// Get a stream of the events received for this player's guild.
+    ///
+    /// See [`LavalinkClient::event_stream`] for details.
+    pub fn event_stream(&self) -> impl Stream<Item = events::NodeEvent> + Send + 'static {
+        let guild_id = self.guild_id;
+
+        self.client
+            .event_stream()
+            .filter(move |event| futures::future::ready(event.event.guild_id() == Some(guild_id)))
     }
 
     /// Get a reference to the current queue.
@@ -95,10 +205,21 @@ impl PlayerContext {
 
         QueueRef {
             tx: self.tx.clone(),
+            queue_event_tx: self.queue_event_tx.clone(),
             stream: std::sync::Arc::new(std::sync::Mutex::new(stream)),
         }
     }
 
+    /// Capture the queue and the player state, to restore them later with
+    /// `LavalinkClient::restore_player_context()`.
+    pub async fn snapshot(&self) -> LavalinkResult<super::PlayerSnapshot> {
+        let (tx, rx) = oneshot::channel();
+
+        self.tx.send(super::PlayerMessage::GetSnapshot(tx))?;
+
+        Ok(rx.await?)
+    }
+
     /// Get the current player information.
     pub async fn get_player(&self) -> LavalinkResult<player::Player> {
         let (tx, rx) = oneshot::channel();
@@ -227,6 +348,77 @@ impl PlayerContext {
         .await
     }
 
+    /// Merge filters into the current filters of the player, and validate the result.
+    ///
+    /// Unlike `set_filters`, the filters that are not set in `filters` are kept.
+    pub async fn merge_filters(&self, filters: player::Filters) -> LavalinkResult<player::Player> {
+        let mut current = self.get_player().await?.filters.unwrap_or_default();
+        current.merge(filters);
+        current.validate()?;
+
+        self.set_filters(current).await
+    }
+
+    /// Fade the volume filter to `volume`, from 0.0 to 5.0 where 1.0 is 100%, over `duration`.
+    ///
+    /// See `transition_filters()` for details.
+    pub async fn fade_volume(
+        &self,
+        volume: f64,
+        duration: std::time::Duration,
+        curve: super::FadeCurve,
+    ) -> LavalinkResult<bool> {
+        let mut filters = self.get_player().await?.filters.unwrap_or_default();
+        filters.volume = Some(volume);
+
+        self.transition_filters_with_curve(filters, duration, curve)
+            .await
+    }
+
+    /// Change the filters of the player gradually over `duration`, as described by
+    /// `Filters::interpolate()`.
+    ///
+    /// The transition runs in the background of the player, and this waits for it to end.
+    /// Returns false if it was cancelled by another transition, or by `cancel_transition()`.
+    pub async fn transition_filters(
+        &self,
+        filters: player::Filters,
+        duration: std::time::Duration,
+    ) -> LavalinkResult<bool> {
+        self.transition_filters_with_curve(filters, duration, super::FadeCurve::Linear)
+            .await
+    }
+
+    /// Change the filters of the player gradually, progressing along `curve`.
+    ///
+    /// See `transition_filters()` for details.
+    pub async fn transition_filters_with_curve(
+        &self,
+        filters: player::Filters,
+        duration: std::time::Duration,
+        curve: super::FadeCurve,
+    ) -> LavalinkResult<bool> {
+        filters.validate()?;
+
+        let (tx, rx) = oneshot::channel();
+
+        self.tx.send(super::PlayerMessage::TransitionFilters(
+            filters, duration, curve, tx,
+        ))?;
+
+        match rx.await {
+            Ok(result) => result.map(|_| true),
+            // The task was aborted before replying.
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Stop the current filter transition, leaving the filters where they are.
+    pub fn cancel_transition(&self) -> LavalinkResult<()> {
+        self.tx.send(super::PlayerMessage::CancelTransition)?;
+        Ok(())
+    }
+
     /// Set the filters of the player.
     pub async fn set_filters(&self, filters: player::Filters) -> LavalinkResult<player::Player> {
         self.update_player(
@@ -291,6 +483,15 @@ impl QueueRef {
         Ok(rx.await?)
     }
 
+    /// Get the tracks that were played, from oldest to newest.
+    pub async fn get_history(&self) -> LavalinkResult<VecDeque<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::GetHistory(tx))?;
+
+        Ok(rx.await?)
+    }
+
     /// Get the amount of tracks in the queue, AKA the queue length.
     pub async fn get_count(&self) -> LavalinkResult<usize> {
         let (tx, rx) = oneshot::channel();
@@ -300,48 +501,198 @@ impl QueueRef {
         Ok(rx.await?)
     }
 
+    /// Get a stream of the changes made to the queue from now on.
+    ///
+    /// If the stream is not polled often enough, the oldest events are skipped and a warning is
+    /// logged.
+    pub fn subscribe(&self) -> impl Stream<Item = super::QueueEvent> + Send + 'static {
+        futures::stream::unfold(self.queue_event_tx.subscribe(), |mut rx| async move {
+            loop {
+                match rx.recv().await {
+                    Ok(event) => return Some((event, rx)),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            "Queue event stream lagged behind, skipped {} events.",
+                            skipped
+                        )
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
     /// Add the track at the end of the queue.
-    pub fn push_to_back(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::PushToBack(track.into()))
+    pub async fn push_to_back(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::PushToBack(track.into(), tx))?;
+
+        rx.await?
     }
 
     /// Add the track at the start of the queue.
-    pub fn push_to_front(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::PushToFront(track.into()))
+    pub async fn push_to_front(&self, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::PushToFront(track.into(), tx))?;
+
+        rx.await?
     }
 
     /// Insert the track at the given index.
-    pub fn insert(
+    ///
+    /// The index can be the length of the queue to insert the track at the end, any index
+    /// after that returns `LavalinkError::IndexOutOfBounds`.
+    pub async fn insert(
         &self,
         index: usize,
         track: impl Into<super::TrackInQueue>,
     ) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Insert(index, track.into()))
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Insert(index, track.into(), tx))?;
+
+        rx.await?
+    }
+
+    /// Remove the track at the given index, and return it.
+    ///
+    /// Returns `LavalinkError::IndexOutOfBounds` if there's no track at the index.
+    pub async fn remove(&self, index: usize) -> LavalinkResult<super::TrackInQueue> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Remove(index, tx))?;
+
+        rx.await?
+    }
+
+    /// Shuffle the queue, and return the tracks in their new order.
+    ///
+    /// The same seed always results in the same order for the same queue.
+    pub async fn shuffle(&self, seed: Option<u64>) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Shuffle(seed, tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Move the track at `from` to `to`, and return it.
+    ///
+    /// Returns `LavalinkError::IndexOutOfBounds` if either index is out of bounds.
+    pub async fn move_track(&self, from: usize, to: usize) -> LavalinkResult<super::TrackInQueue> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Move(from, to, tx))?;
+
+        rx.await?
+    }
+
+    /// Swap the tracks at `a` and `b`, and return them in their new order.
+    ///
+    /// Returns `LavalinkError::IndexOutOfBounds` if either index is out of bounds.
+    pub async fn swap_indices(
+        &self,
+        a: usize,
+        b: usize,
+    ) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::SwapIndices(a, b, tx))?;
+
+        rx.await?
     }
 
-    /// Remove the track at the given index.
-    pub fn remove(&self, index: usize) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Remove(index))
+    /// Remove the tracks in the range, and return them.
+    ///
+    /// The range is truncated to the length of the queue.
+    pub async fn remove_range(
+        &self,
+        range: impl std::ops::RangeBounds<usize>,
+    ) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+
+        self.send(super::QueueMessage::RemoveRange(range, tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Remove the tracks with the same identifier as a track before them, and return them.
+    pub async fn dedupe(&self) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Dedupe(tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Remove the tracks matching the predicate, and return them.
+    pub async fn remove_where(
+        &self,
+        predicate: impl FnMut(&super::TrackInQueue) -> bool + Send + 'static,
+    ) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+        let predicate = super::TrackPredicate(Box::new(predicate));
+
+        self.send(super::QueueMessage::RemoveWhere(predicate, tx))?;
+
+        Ok(rx.await?)
+    }
+
+    /// Skip the first `amount` tracks of the queue and play the next one, returning the skipped
+    /// tracks.
+    ///
+    /// With `LoopMode::Queue`, the skipped tracks are added back to the end of the queue.
+    pub async fn skip(&self, amount: usize) -> LavalinkResult<Vec<super::TrackInQueue>> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Skip(amount, tx))?;
+
+        Ok(rx.await?)
     }
 
     /// Clear the queue.
-    pub fn clear(&self) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Clear)
+    pub async fn clear(&self) -> LavalinkResult<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Clear(tx))?;
+
+        rx.await?
     }
 
     /// Replace the entire queue with a new one.
-    pub fn replace(&self, tracks: VecDeque<super::TrackInQueue>) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Replace(tracks))
+    pub async fn replace(&self, tracks: VecDeque<super::TrackInQueue>) -> LavalinkResult<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Replace(tracks, tx))?;
+
+        rx.await?
     }
 
     /// Append the list at the end of the current queue.
-    pub fn append(&self, tracks: VecDeque<super::TrackInQueue>) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Append(tracks))
+    pub async fn append(&self, tracks: VecDeque<super::TrackInQueue>) -> LavalinkResult<()> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Append(tracks, tx))?;
+
+        rx.await?
     }
 
-    /// Swap the track at the index with a new track.
-    pub fn swap(&self, index: usize, track: impl Into<super::TrackInQueue>) -> LavalinkResult<()> {
-        self.send(super::QueueMessage::Swap(index, track.into()))
+    /// Swap the track at the index with a new track, and return the old one.
+    ///
+    /// Returns `LavalinkError::IndexOutOfBounds` if there's no track at the index.
+    pub async fn swap(
+        &self,
+        index: usize,
+        track: impl Into<super::TrackInQueue>,
+    ) -> LavalinkResult<super::TrackInQueue> {
+        let (tx, rx) = oneshot::channel();
+
+        self.send(super::QueueMessage::Swap(index, track.into(), tx))?;
+
+        rx.await?
     }
 
     /// Send messages to the queue to obtain tracks from it, or modify it.
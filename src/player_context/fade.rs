@@ -0,0 +1,58 @@
+use crate::error::LavalinkResult;
+use crate::model::*;
+
+use std::time::Duration;
+
+/// How often the filters are updated during a transition.
+const FADE_STEP: Duration = Duration::from_millis(250);
+
+/// Interpolate the filters of the player from `from` to `to` over `duration`.
+pub(crate) async fn transition(
+    player: &super::PlayerContext,
+    from: player::Filters,
+    to: player::Filters,
+    duration: Duration,
+    curve: super::FadeCurve,
+) -> LavalinkResult<()> {
+    let steps = (duration.as_millis() / FADE_STEP.as_millis()).max(1) as u32;
+
+    for step in 1..=steps {
+        tokio::time::sleep(duration / steps).await;
+
+        let t = curve.apply(step as f64 / steps as f64);
+        player.set_filters(from.interpolate(&to, t)).await?;
+    }
+
+    Ok(())
+}
+
+/// Ramp the volume filter from `from` to `to` over `duration`, keeping the other filters.
+pub(crate) async fn ramp_volume(
+    player: &super::PlayerContext,
+    filters: player::Filters,
+    from: f64,
+    to: f64,
+    duration: Duration,
+) {
+    let result = transition(
+        player,
+        player::Filters {
+            volume: Some(from),
+            ..filters.clone()
+        },
+        player::Filters {
+            volume: Some(to),
+            ..filters
+        },
+        duration,
+        super::FadeCurve::Linear,
+    )
+    .await;
+
+    if let Err(why) = result {
+        error!(
+            "Error setting the volume filter while fading in player {}: {}",
+            player.guild_id.0, why
+        );
+    }
+}
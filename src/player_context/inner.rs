@@ -1,17 +1,47 @@
+use crate::error::{LavalinkError, LavalinkResult};
 use crate::model::*;
 
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
+use std::ops::Bound;
+
+use std::time::Duration;
 
 use tokio::sync::mpsc::UnboundedReceiver;
+use tokio::task::JoinHandle;
 
 pub(crate) struct PlayerContextInner {
     pub guild_id: GuildId,
     pub queue: VecDeque<super::TrackInQueue>,
     pub player_data: player::Player,
     pub dummy: super::PlayerContext,
-    pub last_should_continue: bool,
+    /// The track currently playing, as it was queued.
+    pub current_track: Option<super::TrackInQueue>,
+    pub loop_mode: super::LoopMode,
+    pub queue_empty_handler: Option<super::QueueEmptyHandler>,
+    /// The tracks that were played, from oldest to newest.
+    pub history: VecDeque<super::TrackInQueue>,
+    pub history_size: usize,
+    pub queue_policy: super::QueuePolicy,
+    pub crossfade: Option<super::Crossfade>,
+    /// The task ramping the volume filter, aborted when the track changes.
+    pub fade_task: Option<JoinHandle<()>>,
+    /// The encoded track that is fading out.
+    pub fading_out: Option<String>,
+    /// The filters from before the fade out, restored on the next track.
+    pub fade_base: Option<player::Filters>,
+    /// The task of the transition started by `PlayerContext::transition_filters()`.
+    pub transition_task: Option<JoinHandle<()>>,
+    /// The task decoding the next track while the current one fades out.
+    pub prefetch_task: Option<JoinHandle<()>>,
+    /// The encoded track that the node couldn't decode when it was prefetched.
+    pub undecodable: Option<String>,
 }
 
+/// How long before the fade out a player update can schedule it.
+///
+/// Lavalink sends player updates every 5 seconds by default.
+const FADE_LOOKAHEAD: Duration = Duration::from_secs(10);
+
 impl PlayerContextInner {
     pub async fn start(mut self, mut rx: UnboundedReceiver<super::PlayerMessage>) {
         tokio::spawn(async move {
@@ -27,76 +57,55 @@ impl PlayerContextInner {
                             );
                         }
                     }
-                    UpdatePlayer(player) => self.player_data = player,
-                    UpdatePlayerTrack(track) => self.player_data.track = track,
-                    UpdatePlayerState(state) => self.player_data.state = state,
+                    UpdatePlayer(mut player) => {
+                        if player.voice.channel_id.is_none() {
+                            player.voice.channel_id = self.player_data.voice.channel_id;
+                        }
 
-                    QueueMessage(queue_message) => {
-                        // Why is this needed...?
-                        //self.queue_init().await;
+                        self.player_data = player
+                    }
+                    UpdatePlayerTrack(track) => {
+                        if let Some(track) = &track {
+                            let is_current = self
+                                .current_track
+                                .as_ref()
+                                .is_some_and(|x| x.track.encoded == track.encoded);
 
-                        use super::QueueMessage::*;
+                            if !is_current {
+                                let previous_track =
+                                    self.current_track.replace(track.clone().into());
 
-                        match queue_message {
-                            GetQueue(tx) => {
-                                if let Err(why) = tx.send(self.queue.clone()) {
-                                    error!(
-                                        "Error sending queue back to the player {}: {}",
-                                        self.guild_id.0, why
-                                    );
+                                if let Some(previous_track) = previous_track {
+                                    self.push_history(previous_track);
                                 }
                             }
-                            GetTrack(index, tx) => {
-                                let track = self.queue.get(index).cloned();
+                        }
 
-                                if let Err(why) = tx.send(track) {
-                                    error!(
-                                        "Error sending track back to the player {}: {}",
-                                        self.guild_id.0, why
-                                    );
-                                }
-                            }
-                            GetCount(tx) => {
-                                if let Err(why) = tx.send(self.queue.len()) {
-                                    error!(
-                                        "Error sending queue length back to the player {}: {}",
-                                        self.guild_id.0, why
-                                    );
+                        self.player_data.track = track
+                    }
+                    UpdatePlayerState(state) => {
+                        self.player_data.state = state;
+                        self.schedule_fade_out();
+                    }
+
+                    QueueMessage(queue_message) => self.handle_queue_message(queue_message),
+
+                    TrackFinished(reason) => {
+                        self.player_data.track = None;
+
+                        if reason == events::TrackEndReason::Finished {
+                            match (self.loop_mode, &self.current_track) {
+                                (super::LoopMode::Track, Some(track)) => {
+                                    self.push_front(track.clone())
                                 }
-                            }
-                            PushToBack(track) => {
-                                self.queue.push_back(track);
-                            }
-                            PushToFront(track) => {
-                                self.queue.push_front(track);
-                            }
-                            Insert(index, track) => {
-                                self.queue.insert(index, track);
-                            }
-                            Remove(index) => {
-                                self.queue.remove(index);
-                            }
-                            Clear => {
-                                self.queue.clear();
-                            }
-                            Replace(tracks) => {
-                                self.queue = tracks;
-                            }
-                            Append(mut tracks) => {
-                                self.queue.append(&mut tracks);
-                            }
-                            Swap(index, track) => {
-                                if let Some(t) = self.queue.get_mut(index) {
-                                    *t = track;
+                                (super::LoopMode::Queue, Some(track)) => {
+                                    self.push_back(track.clone())
                                 }
+                                _ => (),
                             }
                         }
-                    }
 
-                    TrackFinished(should_continue) => {
-                        self.last_should_continue = should_continue;
-
-                        if should_continue {
+                        if bool::from(reason) {
                             if let Err(why) = self.dummy.skip() {
                                 error!(
                                     "Error sending skip message in player {}: {}",
@@ -106,40 +115,764 @@ impl PlayerContextInner {
                         }
                     }
                     StartTrack => {
-                        if let Some(track) = self.queue.pop_front() {
-                            if let Err(why) = self
-                                .dummy
-                                .update_player(&track.into_update_player(), false)
-                                .await
+                        let is_fading = self.fade_task.as_ref().is_some_and(|x| !x.is_finished());
+
+                        if let Some(fade_task) = self.fade_task.take() {
+                            fade_task.abort();
+                        }
+
+                        // A fade out always ends by starting the next track, but an interrupted
+                        // fade in leaves the volume filter halfway.
+                        let fade_base = self
+                            .fade_base
+                            .take()
+                            .filter(|_| is_fading || self.fading_out.is_some());
+                        self.fading_out = None;
+
+                        let last_track = self.current_track.take();
+
+                        if let Some(track) = &last_track {
+                            // Skipping a track that is still playing keeps it in the queue loop.
+                            if self.loop_mode == super::LoopMode::Queue
+                                && self.player_data.track.is_some()
+                            {
+                                self.push_back(track.clone());
+                            }
+
+                            self.push_history(track.clone());
+                        }
+
+                        let mut next_track = self.queue.pop_front();
+
+                        // The node would fail to play it, and leave the player silent after the
+                        // fade out.
+                        if let Some(encoded) = self.undecodable.take() {
+                            if let Some(track) = next_track.take_if(|x| x.track.encoded == encoded)
+                            {
+                                self.notify(|| super::QueueEvent::Removed { index: 0, track });
+                                next_track = self.queue.pop_front();
+                            }
+                        }
+
+                        self.notify(|| super::QueueEvent::Advanced {
+                            track: next_track.clone(),
+                        });
+
+                        if next_track.is_none() {
+                            if let Some(filters) = fade_base.clone() {
+                                if let Err(why) = self.dummy.set_filters(filters).await {
+                                    error!(
+                                        "Error restoring the filters after fading out in player {}: {}",
+                                        self.guild_id.0, why
+                                    );
+                                }
+                            }
+                        }
+
+                        if let Some(track) = next_track {
+                            self.current_track = Some(track.clone());
+
+                            let mut update_player = track.into_update_player();
+
+                            if update_player.filters.is_none() {
+                                update_player.filters = fade_base;
+                            }
+
+                            // Start silent, and fade in to the filters the track should have.
+                            let fade_in = self
+                                .crossfade
+                                .map(|x| x.fade_in)
+                                .filter(|x| !x.is_zero())
+                                .map(|fade_in| {
+                                    let filters = update_player
+                                        .filters
+                                        .clone()
+                                        .or_else(|| self.player_data.filters.clone())
+                                        .unwrap_or_default();
+
+                                    update_player.filters = Some(player::Filters {
+                                        volume: Some(0.0),
+                                        ..filters.clone()
+                                    });
+
+                                    (fade_in, filters)
+                                });
+
+                            if let Err(why) = self.dummy.update_player(&update_player, false).await
                             {
                                 error!(
                                     "Error sending update_player request in player {}: {}",
                                     self.guild_id.0, why
                                 );
+                            } else if let Some((fade_in, filters)) = fade_in {
+                                let player = self.dummy.clone();
+                                let volume = filters.volume.unwrap_or(1.0);
+
+                                self.fade_base = Some(filters.clone());
+
+                                self.fade_task = Some(tokio::spawn(async move {
+                                    super::fade::ramp_volume(&player, filters, 0.0, volume, fade_in)
+                                        .await
+                                }));
                             }
-                        } else {
-                            if let Err(why) = self.dummy.stop_now().await {
+                        } else if let Some(handler) = &self.queue_empty_handler {
+                            let handler = handler.clone();
+                            let player = self.dummy.clone();
+
+                            // The handler may use the player context, so it can't block the actor.
+                            tokio::spawn(async move {
+                                let tracks = handler(player.clone(), last_track).await;
+
+                                let result = if tracks.is_empty() {
+                                    player.stop_now().await.map(|_| ())
+                                } else {
+                                    player
+                                        .get_queue()
+                                        .append(tracks.into())
+                                        .await
+                                        .and_then(|_| player.skip())
+                                };
+
+                                if let Err(why) = result {
+                                    error!(
+                                        "Error playing the tracks from the queue empty handler in player {}: {}",
+                                        player.guild_id.0, why
+                                    );
+                                }
+                            });
+                        } else if let Err(why) = self.dummy.stop_now().await {
+                            error!(
+                                "Error sending stop request in player {}: {}",
+                                self.guild_id.0, why
+                            );
+                        }
+                    }
+                    SetLoopMode(loop_mode) => self.loop_mode = loop_mode,
+                    GetLoopMode(tx) => {
+                        if let Err(why) = tx.send(self.loop_mode) {
+                            error!(
+                                "Error sending loop mode back to the player {}: {}",
+                                self.guild_id.0, why
+                            );
+                        }
+                    }
+                    SetQueueEmptyHandler(handler) => self.queue_empty_handler = handler,
+                    PlayPrevious(tx) => {
+                        let track = self.history.pop_back();
+
+                        if let Some(track) = &track {
+                            if let Some(current_track) = self.current_track.take() {
+                                if self.player_data.track.is_some() {
+                                    self.push_front(current_track);
+                                }
+                            }
+
+                            self.push_front(track.clone());
+
+                            if let Err(why) = self.dummy.skip() {
                                 error!(
-                                    "Error sending stop request in player {}: {}",
+                                    "Error sending skip message in player {}: {}",
                                     self.guild_id.0, why
                                 );
                             }
                         }
+
+                        if let Err(why) = tx.send(track) {
+                            error!(
+                                "Error sending previous track back to the player {}: {}",
+                                self.guild_id.0, why
+                            );
+                        }
+                    }
+                    SetHistorySize(history_size) => {
+                        self.history_size = history_size;
+
+                        while self.history.len() > history_size {
+                            self.history.pop_front();
+                        }
+                    }
+                    GetSnapshot(tx) => {
+                        let snapshot = super::PlayerSnapshot {
+                            player: self.player_data.clone(),
+                            current_track: self.current_track.clone(),
+                            queue: self.queue.iter().cloned().collect(),
+                            history: self.history.iter().cloned().collect(),
+                            loop_mode: self.loop_mode,
+                        };
+
+                        self.reply(tx, snapshot);
+                    }
+                    SetQueuePolicy(queue_policy) => self.queue_policy = queue_policy,
+                    SetCrossfade(crossfade) => self.crossfade = crossfade,
+                    Undecodable(encoded) => self.undecodable = Some(encoded),
+                    TransitionFilters(filters, duration, curve, tx) => {
+                        self.cancel_transition();
+
+                        // The transition takes over the filters, so a fade in or out can't keep
+                        // ramping the volume or restore them.
+                        if let Some(fade_task) = self.fade_task.take() {
+                            fade_task.abort();
+                        }
+
+                        self.fade_base = None;
+
+                        let player = self.dummy.clone();
+                        let from = self.player_data.filters.clone().unwrap_or_default();
+
+                        self.transition_task = Some(tokio::spawn(async move {
+                            let result =
+                                super::fade::transition(&player, from, filters, duration, curve)
+                                    .await;
+
+                            let _ = tx.send(result);
+                        }));
+                    }
+                    CancelTransition => self.cancel_transition(),
+                    GetQueuePolicy(tx) => self.reply(tx, self.queue_policy.clone()),
+                    Close => {
+                        if let Some(fade_task) = self.fade_task.take() {
+                            fade_task.abort();
+                        }
+
+                        if let Some(prefetch_task) = self.prefetch_task.take() {
+                            prefetch_task.abort();
+                        }
+
+                        self.cancel_transition();
+
+                        rx.close()
                     }
-                    Close => rx.close(),
                 };
             }
         });
     }
 
-    //async fn queue_init(&self) {
-    //    if self.last_should_continue && self.player_data.track.is_none() {
-    //        if let Err(why) = self.dummy.skip() {
-    //            error!(
-    //                "Error sending skip message in player {}: {}",
-    //                self.guild_id.0, why
-    //            );
-    //        }
-    //    }
-    //}
+    fn handle_queue_message(&mut self, queue_message: super::QueueMessage) {
+        use super::QueueMessage::*;
+
+        let len = self.queue.len();
+
+        match queue_message {
+            GetQueue(tx) => self.reply(tx, self.queue.clone()),
+            GetTrack(index, tx) => self.reply(tx, self.queue.get(index).cloned()),
+            GetHistory(tx) => self.reply(tx, self.history.clone()),
+            GetCount(tx) => self.reply(tx, len),
+            PushToBack(track, tx) => {
+                let result = self
+                    .check_policy(&track)
+                    .map(|_| self.push_back_fair(track));
+                self.reply(tx, result);
+            }
+            PushToFront(track, tx) => {
+                let result = self.check_policy(&track).map(|_| self.push_front(track));
+                self.reply(tx, result);
+            }
+            Insert(index, track, tx) => {
+                // Inserting right after the last track is the same as pushing to the back.
+                let result = check_index(index, len + 1)
+                    .and_then(|_| self.check_policy(&track))
+                    .map(|_| {
+                        self.notify(|| super::QueueEvent::Inserted {
+                            index,
+                            track: track.clone(),
+                        });
+                        self.queue.insert(index, track)
+                    });
+
+                self.reply(tx, result);
+            }
+            Remove(index, tx) => {
+                let result = check_index(index, len).map(|_| {
+                    let track = self.queue.remove(index).unwrap();
+                    self.notify(|| super::QueueEvent::Removed {
+                        index,
+                        track: track.clone(),
+                    });
+                    track
+                });
+
+                self.reply(tx, result);
+            }
+            Clear(tx) => {
+                self.queue.clear();
+                self.notify(|| super::QueueEvent::Cleared);
+                self.reply(tx, Ok(()));
+            }
+            Replace(mut tracks, tx) => {
+                let result = self
+                    .queue_policy
+                    .check(std::iter::empty(), tracks.make_contiguous())
+                    .map_err(LavalinkError::from)
+                    .map(|_| {
+                        self.queue = tracks;
+                        self.notify_replaced();
+                    });
+
+                self.reply(tx, result);
+            }
+            Append(mut tracks, tx) => {
+                let result = self
+                    .queue_policy
+                    .check(&self.queue, tracks.make_contiguous())
+                    .map_err(LavalinkError::from)
+                    .map(|_| {
+                        if self.queue_policy.fair {
+                            for track in tracks {
+                                self.push_back_fair(track);
+                            }
+                        } else {
+                            self.notify(|| super::QueueEvent::Pushed {
+                                index: len,
+                                tracks: tracks.iter().cloned().collect(),
+                            });
+                            self.queue.append(&mut tracks);
+                        }
+                    });
+
+                self.reply(tx, result);
+            }
+            Swap(index, track, tx) => {
+                let result = check_index(index, len)
+                    .and_then(|_| {
+                        let others = self
+                            .queue
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| *i != index)
+                            .map(|(_, x)| x);
+
+                        Ok(self
+                            .queue_policy
+                            .check(others, std::slice::from_ref(&track))?)
+                    })
+                    .map(|_| {
+                        let old_track = std::mem::replace(&mut self.queue[index], track.clone());
+
+                        self.notify(|| super::QueueEvent::Removed {
+                            index,
+                            track: old_track.clone(),
+                        });
+                        self.notify(|| super::QueueEvent::Inserted { index, track });
+
+                        old_track
+                    });
+
+                self.reply(tx, result);
+            }
+            Shuffle(seed, tx) => {
+                let mut rng = seed.map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed);
+                rng.shuffle(self.queue.make_contiguous());
+                self.notify_replaced();
+                self.reply(tx, self.queue.iter().cloned().collect());
+            }
+            Move(from, to, tx) => {
+                let result = check_index(from, len)
+                    .and_then(|_| check_index(to, len))
+                    .map(|_| {
+                        let track = self.queue.remove(from).unwrap();
+                        self.queue.insert(to, track.clone());
+                        self.notify_replaced();
+                        track
+                    });
+
+                self.reply(tx, result);
+            }
+            SwapIndices(a, b, tx) => {
+                let result = check_index(a, len)
+                    .and_then(|_| check_index(b, len))
+                    .map(|_| {
+                        self.queue.swap(a, b);
+                        self.notify_replaced();
+                        vec![self.queue[a].clone(), self.queue[b].clone()]
+                    });
+
+                self.reply(tx, result);
+            }
+            RemoveRange((start, end), tx) => {
+                let start = match start {
+                    Bound::Included(x) => x,
+                    Bound::Excluded(x) => x.saturating_add(1),
+                    Bound::Unbounded => 0,
+                }
+                .min(len);
+                let end = match end {
+                    Bound::Included(x) => x.saturating_add(1),
+                    Bound::Excluded(x) => x,
+                    Bound::Unbounded => len,
+                }
+                .clamp(start, len);
+
+                let removed: Vec<_> = self.queue.drain(start..end).collect();
+                self.notify_removed(start, &removed);
+
+                self.reply(tx, removed);
+            }
+            Dedupe(tx) => {
+                let mut identifiers = HashSet::new();
+
+                let removed = self
+                    .remove_where(|track| !identifiers.insert(track.track.info.identifier.clone()));
+
+                self.reply(tx, removed);
+            }
+            RemoveWhere(super::TrackPredicate(predicate), tx) => {
+                let removed = self.remove_where(predicate);
+                self.reply(tx, removed);
+            }
+            Skip(amount, tx) => {
+                let amount = amount.min(len);
+                let skipped: Vec<_> = self.queue.drain(..amount).collect();
+                self.notify_removed(0, &skipped);
+
+                if self.loop_mode == super::LoopMode::Queue {
+                    // The track that is playing goes back to the queue before the skipped ones,
+                    // so it's taken here instead of in `StartTrack`.
+                    let current_track = if self.player_data.track.is_some() {
+                        self.current_track.take()
+                    } else {
+                        None
+                    };
+
+                    if let Some(track) = &current_track {
+                        self.push_history(track.clone());
+                    }
+
+                    let tracks: Vec<_> = current_track
+                        .into_iter()
+                        .chain(skipped.iter().cloned())
+                        .collect();
+
+                    if !tracks.is_empty() {
+                        self.notify(|| super::QueueEvent::Pushed {
+                            index: self.queue.len(),
+                            tracks: tracks.clone(),
+                        });
+                        self.queue.extend(tracks);
+                    }
+                }
+
+                if let Err(why) = self.dummy.skip() {
+                    error!(
+                        "Error sending skip message in player {}: {}",
+                        self.guild_id.0, why
+                    );
+                }
+
+                self.reply(tx, skipped);
+            }
+        }
+    }
+
+    /// Start fading out the current track when it gets close enough to its end.
+    fn schedule_fade_out(&mut self) {
+        let Some(crossfade) = self.crossfade.filter(|x| !x.fade_out.is_zero()) else {
+            return;
+        };
+
+        let Some(track) = &self.player_data.track else {
+            return;
+        };
+
+        if track.info.is_stream
+            || self.player_data.paused
+            || self.loop_mode == super::LoopMode::Track
+        {
+            return;
+        }
+
+        let end = self
+            .current_track
+            .as_ref()
+            .filter(|x| x.track.encoded == track.encoded)
+            .and_then(|x| x.end_time)
+            .map_or(track.info.length, |x| x.as_millis() as u64);
+        let remaining = Duration::from_millis(end.saturating_sub(self.player_data.state.position));
+
+        let is_fading_out = self.fading_out.as_ref() == Some(&track.encoded);
+
+        if remaining > crossfade.fade_out + FADE_LOOKAHEAD {
+            // The track was seeked back before the end of the fade out.
+            if is_fading_out {
+                self.cancel_fade_out();
+            }
+
+            return;
+        }
+
+        if is_fading_out {
+            return;
+        }
+
+        let filters = self.player_data.filters.clone().unwrap_or_default();
+        let volume = filters.volume.unwrap_or(1.0);
+        let player = self.dummy.clone();
+
+        self.fading_out = Some(track.encoded.clone());
+        self.fade_base = Some(filters.clone());
+
+        if let Some(fade_task) = self.fade_task.take() {
+            fade_task.abort();
+        }
+
+        self.fade_task = Some(tokio::spawn(async move {
+            tokio::time::sleep(remaining.saturating_sub(crossfade.fade_out)).await;
+
+            super::fade::ramp_volume(
+                &player,
+                filters,
+                volume,
+                0.0,
+                crossfade.fade_out.min(remaining),
+            )
+            .await;
+
+            // Start the next track right away instead of waiting for the end of this one.
+            if let Err(why) = player.skip() {
+                error!(
+                    "Error sending skip message in player {}: {}",
+                    player.guild_id.0, why
+                );
+            }
+        }));
+
+        self.prefetch_next_track();
+    }
+
+    /// Decode the track at the front of the queue with the node, so it can be skipped before the
+    /// fade out ends if it can't be played.
+    fn prefetch_next_track(&mut self) {
+        if let Some(prefetch_task) = self.prefetch_task.take() {
+            prefetch_task.abort();
+        }
+
+        let Some(next_track) = self.queue.front() else {
+            return;
+        };
+
+        let encoded = next_track.track.encoded.clone();
+        let player = self.dummy.clone();
+
+        self.prefetch_task = Some(tokio::spawn(async move {
+            let node = player.client.get_node_for_guild(player.guild_id).await;
+
+            // Only the node rejecting the track means it can't be played, the other errors may
+            // not happen again when it starts.
+            match node.http.decode_track(&encoded).await {
+                Err(why @ (LavalinkError::BadRequest(_) | LavalinkError::ResponseError(_))) => {
+                    warn!(
+                        "Skipping the next track of player {}, it could not be decoded: {}",
+                        player.guild_id.0, why
+                    );
+
+                    if let Err(why) = player.tx.send(super::PlayerMessage::Undecodable(encoded)) {
+                        error!(
+                            "Error sending undecodable track to the player {}: {}",
+                            player.guild_id.0, why
+                        );
+                    }
+                }
+                Err(why) => warn!(
+                    "Error prefetching the next track of player {}: {}",
+                    player.guild_id.0, why
+                ),
+                Ok(_) => (),
+            }
+        }));
+    }
+
+    fn cancel_transition(&mut self) {
+        if let Some(transition_task) = self.transition_task.take() {
+            transition_task.abort();
+        }
+    }
+
+    fn cancel_fade_out(&mut self) {
+        if let Some(fade_task) = self.fade_task.take() {
+            fade_task.abort();
+        }
+
+        self.fading_out = None;
+
+        if let Some(filters) = self.fade_base.take() {
+            let player = self.dummy.clone();
+
+            tokio::spawn(async move {
+                if let Err(why) = player.set_filters(filters).await {
+                    error!(
+                        "Error restoring the filters after fading out in player {}: {}",
+                        player.guild_id.0, why
+                    );
+                }
+            });
+        }
+    }
+
+    fn reply<T>(&self, tx: oneshot::Sender<T>, value: T) {
+        if tx.send(value).is_err() {
+            error!("Error sending reply back to the player {}", self.guild_id.0);
+        }
+    }
+
+    fn remove_where(
+        &mut self,
+        mut predicate: impl FnMut(&super::TrackInQueue) -> bool,
+    ) -> Vec<super::TrackInQueue> {
+        let mut index = 0;
+        let mut removed = vec![];
+
+        self.queue.retain(|track| {
+            index += 1;
+
+            if predicate(track) {
+                removed.push((index - 1, track.clone()));
+                false
+            } else {
+                true
+            }
+        });
+
+        // Notify from the last index, so the indexes of the next events are still valid.
+        for (index, track) in removed.iter().rev() {
+            self.notify(|| super::QueueEvent::Removed {
+                index: *index,
+                track: track.clone(),
+            });
+        }
+
+        removed.into_iter().map(|(_, track)| track).collect()
+    }
+
+    fn check_policy(&self, track: &super::TrackInQueue) -> LavalinkResult<()> {
+        Ok(self
+            .queue_policy
+            .check(&self.queue, std::slice::from_ref(track))?)
+    }
+
+    fn push_back(&mut self, track: super::TrackInQueue) {
+        self.notify(|| super::QueueEvent::Pushed {
+            index: self.queue.len(),
+            tracks: vec![track.clone()],
+        });
+        self.queue.push_back(track);
+    }
+
+    /// Add the track to the back of the queue, or after the tracks of the same round if the queue
+    /// is fair.
+    fn push_back_fair(&mut self, track: super::TrackInQueue) {
+        if !self.queue_policy.fair {
+            return self.push_back(track);
+        }
+
+        let index = self.fair_index(&track);
+
+        self.notify(|| super::QueueEvent::Inserted {
+            index,
+            track: track.clone(),
+        });
+        self.queue.insert(index, track);
+    }
+
+    /// Find where the track goes in a fair queue.
+    ///
+    /// The round of a track is the amount of tracks with the same requester before it, including
+    /// the one playing. The track goes after the last track of its round or an earlier one.
+    fn fair_index(&self, track: &super::TrackInQueue) -> usize {
+        let policy = &self.queue_policy;
+        let requester = policy.requester(track);
+
+        let current_track = self
+            .current_track
+            .as_ref()
+            .filter(|_| self.player_data.track.is_some());
+
+        let round = current_track
+            .into_iter()
+            .chain(&self.queue)
+            .filter(|x| policy.requester(x) == requester)
+            .count();
+
+        // `serde_json::Value` is not `Hash`, and there are rarely more than a few requesters.
+        let mut rounds: Vec<(Option<&serde_json::Value>, usize)> = vec![];
+        let mut next_round = |track| {
+            let requester = policy.requester(track);
+
+            match rounds.iter_mut().find(|x| x.0 == requester) {
+                Some(x) => {
+                    x.1 += 1;
+                    x.1 - 1
+                }
+                None => {
+                    rounds.push((requester, 1));
+                    0
+                }
+            }
+        };
+
+        if let Some(current_track) = current_track {
+            next_round(current_track);
+        }
+
+        let mut index = 0;
+
+        for (i, x) in self.queue.iter().enumerate() {
+            if next_round(x) <= round {
+                index = i + 1;
+            }
+        }
+
+        index
+    }
+
+    fn push_front(&mut self, track: super::TrackInQueue) {
+        self.notify(|| super::QueueEvent::Pushed {
+            index: 0,
+            tracks: vec![track.clone()],
+        });
+        self.queue.push_front(track);
+    }
+
+    /// Send a queue event to the subscribers, if there are any.
+    fn notify(&self, event: impl FnOnce() -> super::QueueEvent) {
+        if self.dummy.queue_event_tx.receiver_count() > 0 {
+            let _ = self.dummy.queue_event_tx.send(event());
+        }
+    }
+
+    fn notify_replaced(&self) {
+        self.notify(|| super::QueueEvent::Replaced {
+            tracks: self.queue.iter().cloned().collect(),
+        });
+    }
+
+    /// Notify the removal of the tracks that were at `start..start + tracks.len()`, starting from
+    /// the last one.
+    fn notify_removed(&self, start: usize, tracks: &[super::TrackInQueue]) {
+        for (index, track) in tracks.iter().enumerate().rev() {
+            self.notify(|| super::QueueEvent::Removed {
+                index: start + index,
+                track: track.clone(),
+            });
+        }
+    }
+
+    fn push_history(&mut self, track: super::TrackInQueue) {
+        if self.history_size == 0 {
+            return;
+        }
+
+        if self.history.len() >= self.history_size {
+            self.history.pop_front();
+        }
+
+        self.history.push_back(track);
+    }
+}
+
+/// Check that the index is lower than the length.
+fn check_index(index: usize, len: usize) -> LavalinkResult<()> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(LavalinkError::IndexOutOfBounds { index, len })
+    }
 }
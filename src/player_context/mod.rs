@@ -1,15 +1,20 @@
 mod context;
+mod fade;
 mod inner;
 
+use crate::error::{LavalinkResult, QueueRejection};
 use crate::model::*;
 
 use std::collections::VecDeque;
+use std::ops::Bound;
+use std::sync::Arc;
 
 pub use context::PlayerContext;
 pub use context::QueueRef;
 pub(crate) use inner::PlayerContextInner;
 
-#[derive(PartialEq, Debug, Clone, Default)]
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 /// A track that's inside the queue.
 pub struct TrackInQueue {
@@ -25,6 +30,252 @@ pub struct TrackInQueue {
     pub filters: Option<player::Filters>,
 }
 
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+/// How the queue is looped when tracks finish.
+pub enum LoopMode {
+    /// Tracks are removed from the queue once they start playing.
+    #[default]
+    Off,
+    /// The current track is played again when it finishes.
+    Track,
+    /// Tracks are added back to the end of the queue when they finish or get skipped.
+    Queue,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// The state of a player context, used to recreate it after a restart with
+/// `LavalinkClient::restore_player_context()`.
+pub struct PlayerSnapshot {
+    /// The player, with the track, position, pause state, volume and filters.
+    pub player: player::Player,
+    /// The track currently playing, as it was queued.
+    pub current_track: Option<TrackInQueue>,
+    /// The tracks in the queue.
+    pub queue: Vec<TrackInQueue>,
+    /// The tracks that were played, from oldest to newest.
+    pub history: Vec<TrackInQueue>,
+    pub loop_mode: LoopMode,
+}
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Fades between the tracks of the queue, by ramping the volume filter.
+///
+/// Lavalink can't play two tracks at once, so the tracks fade one after the other.
+///
+/// When the fade out is scheduled, the next track in the queue is prefetched by decoding it with
+/// the node, and it's skipped if the node rejects it, instead of leaving the player silent after
+/// the fade out. Lavalink has no way to buffer a track before it's played, so a short gap is left
+/// between the fade out and the fade in, while Lavalink starts the next track.
+pub struct Crossfade {
+    /// How long the end of a track fades out.
+    ///
+    /// The fade out is scheduled from the positions sent by Lavalink, and the next track starts
+    /// as soon as it ends, without waiting for the track to finish.
+    pub fade_out: std::time::Duration,
+    /// How long the start of a track fades in.
+    pub fade_in: std::time::Duration,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "python", pyo3::pyclass(eq, eq_int))]
+/// How a fade progresses over time.
+pub enum FadeCurve {
+    /// At a constant rate.
+    #[default]
+    Linear,
+    /// Slowly at first, then faster.
+    EaseIn,
+    /// Quickly at first, then slower.
+    EaseOut,
+    /// Slowly at the start and the end.
+    EaseInOut,
+}
+
+impl FadeCurve {
+    /// Map the elapsed fraction of the fade, from 0.0 to 1.0, to its progress.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            FadeCurve::Linear => t,
+            FadeCurve::EaseIn => t * t,
+            FadeCurve::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            FadeCurve::EaseInOut => t * t * (3.0 - 2.0 * t),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "python", pyo3::pyclass(get_all, set_all))]
+/// Limits on the tracks that can be added to the queue of a player.
+///
+/// The limits only apply to the tracks added by the queue operations, the tracks added back by
+/// `LoopMode` or `PlayerContext::previous()` are never rejected.
+pub struct QueuePolicy {
+    /// The maximum amount of tracks in the queue.
+    pub max_length: Option<usize>,
+    /// The maximum total duration of the tracks in the queue.
+    ///
+    /// Streams have no duration, so they are not counted.
+    pub max_duration: Option<std::time::Duration>,
+    /// The maximum amount of tracks in the queue with the same requester.
+    pub max_tracks_per_requester: Option<usize>,
+    /// The key of `TrackData::user_data` containing the requester of the track.
+    ///
+    /// Defaults to `requester_id`. Tracks without it are not counted.
+    pub requester_key: String,
+    /// Whether streams are rejected.
+    pub reject_streams: bool,
+    /// Whether the tracks pushed to the back of the queue are interleaved between requesters, so
+    /// that the queue plays one track of each requester in turn.
+    ///
+    /// The tracks without a requester are treated as if they had the same requester.
+    pub fair: bool,
+}
+
+impl Default for QueuePolicy {
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            max_duration: None,
+            max_tracks_per_requester: None,
+            requester_key: "requester_id".to_string(),
+            reject_streams: false,
+            fair: false,
+        }
+    }
+}
+
+impl QueuePolicy {
+    /// Check if the tracks can be added to a queue that contains `queue`.
+    pub fn check<'a>(
+        &self,
+        queue: impl IntoIterator<Item = &'a TrackInQueue>,
+        tracks: &[TrackInQueue],
+    ) -> Result<(), QueueRejection> {
+        if self.reject_streams {
+            if let Some(track) = tracks.iter().find(|x| x.track.info.is_stream) {
+                return Err(QueueRejection::StreamNotAllowed {
+                    identifier: track.track.info.identifier.clone(),
+                });
+            }
+        }
+
+        if self.max_length.is_none()
+            && self.max_duration.is_none()
+            && self.max_tracks_per_requester.is_none()
+        {
+            return Ok(());
+        }
+
+        let mut length = tracks.len();
+        let mut duration = tracks
+            .iter()
+            .fold(0u64, |acc, x| acc.saturating_add(Self::duration(x)));
+        // `serde_json::Value` is not `Hash`, and there are rarely more than a few requesters.
+        let mut requesters: Vec<(&serde_json::Value, usize)> = vec![];
+
+        for track in tracks {
+            if let Some(requester) = self.requester(track) {
+                match requesters.iter_mut().find(|x| x.0 == requester) {
+                    Some(x) => x.1 += 1,
+                    None => requesters.push((requester, 1)),
+                }
+            }
+        }
+
+        for track in queue {
+            length += 1;
+            duration = duration.saturating_add(Self::duration(track));
+
+            if let Some(requester) = self.requester(track) {
+                if let Some(x) = requesters.iter_mut().find(|x| x.0 == requester) {
+                    x.1 += 1;
+                }
+            }
+        }
+
+        if let Some(max_length) = self.max_length {
+            if length > max_length {
+                return Err(QueueRejection::TooManyTracks { max_length });
+            }
+        }
+
+        if let Some(max_duration) = self.max_duration {
+            if duration > max_duration.as_millis() as u64 {
+                return Err(QueueRejection::TooLong { max_duration });
+            }
+        }
+
+        if let Some(max_tracks) = self.max_tracks_per_requester {
+            if let Some((requester, _)) = requesters.iter().find(|x| x.1 > max_tracks) {
+                return Err(QueueRejection::RequesterQuotaExceeded {
+                    requester: (*requester).clone(),
+                    max_tracks,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The duration of a track in milliseconds, streams report `i64::MAX` as their length.
+    fn duration(track: &TrackInQueue) -> u64 {
+        if track.track.info.is_stream {
+            0
+        } else {
+            track.track.info.length
+        }
+    }
+
+    pub(crate) fn requester<'a>(&self, track: &'a TrackInQueue) -> Option<&'a serde_json::Value> {
+        track.track.user_data.as_ref()?.get(&self.requester_key)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+/// A change made to the queue, received with `QueueRef::subscribe()`.
+///
+/// Applying the events in order to a copy of the queue keeps it identical to the queue of the
+/// player.
+pub enum QueueEvent {
+    /// Tracks were added to the queue, the first one at the index.
+    Pushed {
+        index: usize,
+        tracks: Vec<TrackInQueue>,
+    },
+    /// A track was inserted at the index.
+    Inserted { index: usize, track: TrackInQueue },
+    /// The track at the index was removed.
+    Removed { index: usize, track: TrackInQueue },
+    /// Every track was removed.
+    Cleared,
+    /// The queue was replaced or reordered.
+    Replaced { tracks: Vec<TrackInQueue> },
+    /// A new track is being started, and was taken from the front of the queue.
+    ///
+    /// None if the queue was empty.
+    Advanced { track: Option<TrackInQueue> },
+}
+
+/// The amount of queue events kept for the subscribers before the slowest ones start missing
+/// events.
+pub(crate) const QUEUE_EVENT_CAPACITY: usize = 128;
+
+/// Called when the queue is empty, with the last track that was played.
+pub(crate) type QueueEmptyHandler = Arc<
+    dyn Fn(PlayerContext, Option<TrackInQueue>) -> BoxFuture<'static, Vec<TrackInQueue>>
+        + Send
+        + Sync,
+>;
+
+/// The amount of played tracks kept in the history of a player by default.
+pub const DEFAULT_HISTORY_SIZE: usize = 50;
+
 pub(crate) enum PlayerMessage {
     GetPlayer(oneshot::Sender<player::Player>),
     UpdatePlayer(player::Player),
@@ -33,8 +284,27 @@ pub(crate) enum PlayerMessage {
 
     QueueMessage(QueueMessage),
 
-    TrackFinished(bool),
+    TrackFinished(events::TrackEndReason),
     StartTrack,
+    SetLoopMode(LoopMode),
+    GetLoopMode(oneshot::Sender<LoopMode>),
+    SetQueueEmptyHandler(Option<QueueEmptyHandler>),
+    PlayPrevious(oneshot::Sender<Option<TrackInQueue>>),
+    SetHistorySize(usize),
+    SetQueuePolicy(QueuePolicy),
+    SetCrossfade(Option<Crossfade>),
+    /// The node couldn't decode the prefetched track, so it's skipped if it's next.
+    Undecodable(String),
+    /// Interpolate the filters to the target, replying once the transition ends.
+    TransitionFilters(
+        player::Filters,
+        std::time::Duration,
+        FadeCurve,
+        oneshot::Sender<LavalinkResult<()>>,
+    ),
+    CancelTransition,
+    GetQueuePolicy(oneshot::Sender<QueuePolicy>),
+    GetSnapshot(oneshot::Sender<PlayerSnapshot>),
     Close,
 }
 
@@ -46,22 +316,59 @@ pub enum QueueMessage {
     GetTrack(usize, oneshot::Sender<Option<TrackInQueue>>),
     /// Get the length of the queue
     GetCount(oneshot::Sender<usize>),
+    /// Clone the history of played tracks and return it, from oldest to newest.
+    GetHistory(oneshot::Sender<VecDeque<TrackInQueue>>),
     /// Add a track to the end of the queue.
-    PushToBack(TrackInQueue),
+    PushToBack(TrackInQueue, oneshot::Sender<LavalinkResult<()>>),
     /// Add a track to the start of the queue.
-    PushToFront(TrackInQueue),
+    PushToFront(TrackInQueue, oneshot::Sender<LavalinkResult<()>>),
     /// Insert a track to a specific position in the queue.
-    Insert(usize, TrackInQueue),
-    /// Remove a track from the queue.
-    Remove(usize),
+    Insert(usize, TrackInQueue, oneshot::Sender<LavalinkResult<()>>),
+    /// Remove a track from the queue, and return it.
+    Remove(usize, oneshot::Sender<LavalinkResult<TrackInQueue>>),
     /// Clear the queue.
-    Clear,
+    Clear(oneshot::Sender<LavalinkResult<()>>),
     /// Replace the entire queue with another one.
-    Replace(VecDeque<TrackInQueue>),
+    Replace(VecDeque<TrackInQueue>, oneshot::Sender<LavalinkResult<()>>),
     /// Append a queue to the end of the current one.
-    Append(VecDeque<TrackInQueue>),
-    /// Swap the track at the index with the new track.
-    Swap(usize, TrackInQueue),
+    Append(VecDeque<TrackInQueue>, oneshot::Sender<LavalinkResult<()>>),
+    /// Swap the track at the index with the new track, and return the old one.
+    Swap(
+        usize,
+        TrackInQueue,
+        oneshot::Sender<LavalinkResult<TrackInQueue>>,
+    ),
+    /// Shuffle the queue, with a seed to make the order reproducible, and return the new queue.
+    Shuffle(Option<u64>, oneshot::Sender<Vec<TrackInQueue>>),
+    /// Move the track at the first index to the second index, and return it.
+    Move(usize, usize, oneshot::Sender<LavalinkResult<TrackInQueue>>),
+    /// Swap the tracks at both indexes, and return them.
+    SwapIndices(
+        usize,
+        usize,
+        oneshot::Sender<LavalinkResult<Vec<TrackInQueue>>>,
+    ),
+    /// Remove the tracks in the range, and return them.
+    RemoveRange(
+        (Bound<usize>, Bound<usize>),
+        oneshot::Sender<Vec<TrackInQueue>>,
+    ),
+    /// Remove the tracks with the same identifier as a previous track, and return them.
+    Dedupe(oneshot::Sender<Vec<TrackInQueue>>),
+    /// Remove the tracks matching the predicate, and return them.
+    RemoveWhere(TrackPredicate, oneshot::Sender<Vec<TrackInQueue>>),
+    /// Skip the amount of tracks at the start of the queue and play the next one, returning the
+    /// skipped tracks.
+    Skip(usize, oneshot::Sender<Vec<TrackInQueue>>),
+}
+
+/// A predicate to match tracks in the queue.
+pub struct TrackPredicate(pub Box<dyn FnMut(&TrackInQueue) -> bool + Send>);
+
+impl std::fmt::Debug for TrackPredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TrackPredicate")
+    }
 }
 
 impl TrackInQueue {
@@ -11,6 +11,8 @@ use pyo3::types::PyList;
 #[pymodule]
 pub fn client(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::client::LavalinkClient>()?;
+    m.add_class::<crate::client::LoadCacheConfig>()?;
+    m.add_class::<crate::client::LoadCacheStats>()?;
 
     Ok(())
 }
@@ -62,7 +64,7 @@ impl crate::client::LavalinkClient {
                         strategy.inner,
                         std::sync::Arc::new(RwLock::new(data)),
                     )
-                    .await)
+                    .await?)
                 } else {
                     Ok(crate::client::LavalinkClient::new_with_data(
                         events,
@@ -70,7 +72,7 @@ impl crate::client::LavalinkClient {
                         strategy.inner,
                         std::sync::Arc::new(RwLock::new(Python::with_gil(|py| py.None()))),
                     )
-                    .await)
+                    .await?)
                 }
             },
         )
@@ -170,6 +172,43 @@ impl crate::client::LavalinkClient {
             .map(|x| super::node::Node { inner: x })
     }
 
+    #[pyo3(name = "get_node_by_id")]
+    fn get_node_by_id_py(&self, id: usize) -> Option<super::node::Node> {
+        self.get_node_by_id(id)
+            .map(|x| super::node::Node { inner: x })
+    }
+
+    #[pyo3(name = "add_node")]
+    fn add_node_py<'a>(
+        &self,
+        py: Python<'a>,
+        node: crate::node::NodeBuilder,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let res = client.add_node(node).await?;
+
+            Ok(Python::with_gil(|_py| super::node::Node { inner: res }))
+        })
+    }
+
+    #[pyo3(name = "remove_node")]
+    fn remove_node_py<'a>(
+        &self,
+        py: Python<'a>,
+        id: usize,
+        drain: bool,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let res = client.remove_node(id, drain).await?;
+
+            Ok(Python::with_gil(|_py| super::node::Node { inner: res }))
+        })
+    }
+
     #[pyo3(name = "get_node_for_guild")]
     pub fn get_node_for_guild_py<'a>(
         &self,
@@ -185,6 +224,22 @@ impl crate::client::LavalinkClient {
         })
     }
 
+    #[pyo3(name = "move_player")]
+    fn move_player_py<'a>(
+        &self,
+        py: Python<'a>,
+        guild_id: super::model::PyGuildId,
+        target_node: super::node::Node,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let player = client.move_player(guild_id, target_node.inner).await?;
+
+            Ok(player)
+        })
+    }
+
     #[pyo3(name = "load_tracks")]
     fn load_tracks_py<'a>(
         &self,
@@ -224,6 +279,33 @@ impl crate::client::LavalinkClient {
         })
     }
 
+    #[pyo3(name = "set_failover")]
+    fn set_failover_py(&self, enabled: bool) {
+        self.set_failover(enabled)
+    }
+
+    #[pyo3(name = "failover_enabled")]
+    fn failover_enabled_py(&self) -> bool {
+        self.failover_enabled()
+    }
+
+    #[pyo3(name = "set_load_cache", signature = (config=None))]
+    fn set_load_cache_py(&self, config: Option<crate::client::LoadCacheConfig>) {
+        self.set_load_cache(config)
+    }
+
+    #[pyo3(name = "load_cache_stats")]
+    fn load_cache_stats_py(&self) -> Option<crate::client::LoadCacheStats> {
+        self.load_cache().map(|load_cache| load_cache.stats())
+    }
+
+    #[pyo3(name = "clear_load_cache")]
+    fn clear_load_cache_py(&self) {
+        if let Some(load_cache) = self.load_cache() {
+            load_cache.clear();
+        }
+    }
+
     #[pyo3(name = "delete_player")]
     fn delete_player_py<'a>(
         &self,
@@ -239,6 +321,54 @@ impl crate::client::LavalinkClient {
         })
     }
 
+    #[pyo3(name = "restore_player_context")]
+    #[pyo3(signature = (snapshot, endpoint, token, session_id, channel_id, user_data=None))]
+    // Takes the connection info as separate arguments, like `create_player_context`.
+    #[allow(clippy::too_many_arguments)]
+    fn restore_player_context_py<'a>(
+        &self,
+        py: Python<'a>,
+        snapshot: crate::player_context::PlayerSnapshot,
+        endpoint: String,
+        token: String,
+        session_id: String,
+        channel_id: super::model::PyChannelId,
+        user_data: Option<PyObject>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.clone();
+        let user_data = user_data.unwrap_or_else(|| py.None());
+
+        pyo3_async_runtimes::tokio::future_into_py_with_locals(
+            py,
+            pyo3_async_runtimes::tokio::get_current_locals(py)?,
+            async move {
+                Ok(client
+                    .restore_player_context_with_data(
+                        snapshot,
+                        ConnectionInfo {
+                            endpoint,
+                            token,
+                            session_id,
+                            channel_id: Some(channel_id.into()),
+                        },
+                        std::sync::Arc::new(RwLock::new(user_data)),
+                    )
+                    .await?)
+            },
+        )
+    }
+
+    #[pyo3(name = "snapshot_all")]
+    fn snapshot_all_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let client = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let snapshots = client.snapshot_all().await?;
+
+            Ok(Python::with_gil(|_py| snapshots))
+        })
+    }
+
     #[pyo3(name = "delete_all_player_contexts")]
     fn delete_all_player_contexts_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
         let client = self.clone();
@@ -434,3 +564,11 @@ impl crate::client::LavalinkClient {
         })
     }
 }
+
+#[pymethods]
+impl crate::client::LoadCacheConfig {
+    #[new]
+    fn new_py() -> crate::client::LoadCacheConfig {
+        crate::client::LoadCacheConfig::default()
+    }
+}
@@ -56,6 +56,24 @@ impl EventHandler {
     #[pyo3(text_signature = "($self, client, session_id, event, /)")]
     /// Event that triggers when the connection is ready.
     fn ready(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers when the connection state of a node changes.
+    fn node_state_update(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers when a node is connected with a new session.
+    fn node_connected(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers when the connection to a node is lost.
+    fn node_disconnected(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers before every attempt to reconnect to a node.
+    fn node_reconnecting(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers when a node is connected and the previous session was resumed.
+    fn node_resumed(&self) {}
+    #[pyo3(text_signature = "($self, client, session_id, event, /)")]
+    /// Event that triggers when an event unknown to the library is received, like the ones sent by plugins.
+    fn plugin_event(&self) {}
 }
 
 impl EventHandler {
@@ -123,6 +141,54 @@ impl EventHandler {
     ) {
         call_event(self, client, session_id, event, "ready");
     }
+    pub(crate) async fn event_node_state_update(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: NodeStateUpdate,
+    ) {
+        call_event(self, client, session_id, event, "node_state_update");
+    }
+    pub(crate) async fn event_node_connected(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: NodeConnected,
+    ) {
+        call_event(self, client, session_id, event, "node_connected");
+    }
+    pub(crate) async fn event_node_disconnected(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: NodeDisconnected,
+    ) {
+        call_event(self, client, session_id, event, "node_disconnected");
+    }
+    pub(crate) async fn event_node_reconnecting(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: NodeReconnecting,
+    ) {
+        call_event(self, client, session_id, event, "node_reconnecting");
+    }
+    pub(crate) async fn event_node_resumed(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: NodeResumed,
+    ) {
+        call_event(self, client, session_id, event, "node_resumed");
+    }
+    pub(crate) async fn event_plugin_event(
+        &self,
+        client: LavalinkClient,
+        session_id: String,
+        event: PluginEvent,
+    ) {
+        call_event(self, client, session_id, event, "plugin_event");
+    }
 }
 
 fn call_event<T: Send + Sync + for<'a> pyo3::IntoPyObject<'a> + 'static>(
@@ -1,10 +1,11 @@
-use crate::model::client::NodeDistributionStrategy;
+use crate::model::client::{NodeDistributionStrategy, NodeState};
 use pyo3::prelude::*;
 
 pub fn client(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     let client = PyModule::new(py, "client")?;
 
     client.add_class::<NodeDistributionStrategyPy>()?;
+    client.add_class::<NodeState>()?;
 
     m.add_submodule(&client)?;
 
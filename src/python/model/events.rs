@@ -1,6 +1,7 @@
 use crate::model::events::*;
 
 use pyo3::prelude::*;
+use pythonize::{depythonize, pythonize};
 
 #[pymodule]
 pub fn events(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -16,6 +17,31 @@ pub fn events(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<TrackException>()?;
     m.add_class::<TrackStuck>()?;
     m.add_class::<WebSocketClosed>()?;
+    m.add_class::<NodeStateUpdate>()?;
+    m.add_class::<NodeConnected>()?;
+    m.add_class::<NodeDisconnected>()?;
+    m.add_class::<NodeReconnecting>()?;
+    m.add_class::<NodeResumed>()?;
+    m.add_class::<PluginEvent>()?;
 
     Ok(())
 }
+
+#[apply(crate::python::with_getter_setter)]
+#[pymethods]
+impl PluginEvent {
+    getter_setter!(
+        (event_type, String),
+        (guild_id, Option<crate::model::GuildId>),
+    );
+
+    #[getter(raw)]
+    fn get_raw(&self, py: Python<'_>) -> PyObject {
+        pythonize(py, &self.raw).unwrap().into()
+    }
+
+    #[setter(raw)]
+    fn set_raw(&mut self, py: Python<'_>, input: PyObject) {
+        self.raw = depythonize(&input.into_bound(py)).unwrap()
+    }
+}
@@ -18,6 +18,7 @@ pub fn player(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     player.add_class::<Rotation>()?;
     player.add_class::<Timescale>()?;
     player.add_class::<TremoloVibrato>()?;
+    player.add_class::<FilterPreset>()?;
 
     m.add_submodule(&player)?;
 
@@ -141,4 +142,28 @@ impl Filters {
     fn set_plugin_filters(&mut self, py: Python<'_>, input: PyObject) {
         self.plugin_filters = depythonize(&input.into_bound(py)).unwrap()
     }
+
+    #[pyo3(name = "merge")]
+    fn merge_py(&mut self, other: Filters) {
+        self.merge(other)
+    }
+
+    #[pyo3(name = "validate")]
+    fn validate_py(&self) -> PyResult<()> {
+        Ok(self.validate()?)
+    }
+}
+
+#[pymethods]
+impl FilterPreset {
+    #[staticmethod]
+    #[pyo3(name = "all")]
+    fn all_py() -> Vec<FilterPreset> {
+        FilterPreset::ALL.to_vec()
+    }
+
+    #[pyo3(name = "filters")]
+    fn filters_py(&self) -> Filters {
+        self.filters()
+    }
 }
@@ -28,6 +28,12 @@ pub(crate) struct Track {
 impl TrackData {
     getter_setter!((encoded, String), (info, TrackInfo),);
 
+    #[staticmethod]
+    #[pyo3(name = "from_encoded")]
+    fn from_encoded_py(encoded: String) -> PyResult<Self> {
+        Ok(TrackData::from_encoded(encoded)?)
+    }
+
     #[getter(plugin_info)]
     fn get_plugin_info(&self, py: Python<'_>) -> PyObject {
         pythonize(py, &self.plugin_info).unwrap().into()
@@ -49,6 +55,15 @@ impl TrackData {
     }
 }
 
+#[pymethods]
+impl TrackInfo {
+    /// Encode the track info into a base64 encoded track.
+    #[pyo3(name = "encode")]
+    fn encode_py(&self) -> PyResult<String> {
+        Ok(encoded::encode(self)?)
+    }
+}
+
 #[apply(crate::python::with_getter_setter)]
 #[pymethods]
 impl PlaylistData {
@@ -8,6 +8,8 @@ use pyo3::prelude::*;
 pub fn node(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Node>()?;
     m.add_class::<crate::node::NodeBuilder>()?;
+    m.add_class::<crate::node::ReconnectPolicy>()?;
+    m.add_class::<crate::http::HttpConfig>()?;
 
     Ok(())
 }
@@ -20,12 +22,22 @@ pub(crate) struct Node {
 
 #[pymethods]
 impl Node {
+    #[getter]
+    fn id(&self) -> usize {
+        self.inner.id
+    }
+
     #[getter]
     fn http(&self) -> super::http::Http {
         super::http::Http {
             inner: self.inner.http.clone(),
         }
     }
+
+    #[getter]
+    fn state(&self) -> crate::model::client::NodeState {
+        self.inner.state()
+    }
 }
 
 #[apply(super::with_getter_setter)]
@@ -37,10 +49,17 @@ impl crate::node::NodeBuilder {
         (password, String),
         (user_id, crate::model::UserId),
         (session_id, Option<String>),
+        (resume_timeout, Option<u32>),
+        (reconnect_policy, crate::node::ReconnectPolicy),
+        (http_config, crate::http::HttpConfig),
+        (extra_headers, Vec<(String, String)>),
     );
 
+    // Every field of the builder is an argument of the Python constructor, except the connector,
+    // which wraps a Rust type and can't be created from Python.
+    #[allow(clippy::too_many_arguments)]
     #[new]
-    #[pyo3(signature = (hostname, is_ssl, password, user_id, session_id=None, events=None))]
+    #[pyo3(signature = (hostname, is_ssl, password, user_id, session_id=None, events=None, resume_timeout=None, reconnect_policy=None, http_config=None, extra_headers=None))]
     fn new(
         py: Python<'_>,
         hostname: String,
@@ -49,6 +68,10 @@ impl crate::node::NodeBuilder {
         user_id: super::model::PyUserId,
         session_id: Option<String>,
         events: Option<PyObject>,
+        resume_timeout: Option<u32>,
+        reconnect_policy: Option<crate::node::ReconnectPolicy>,
+        http_config: Option<crate::http::HttpConfig>,
+        extra_headers: Option<Vec<(String, String)>>,
     ) -> PyResult<Self> {
         let events = if let Some(events) = events {
             let current_loop = pyo3_async_runtimes::get_running_loop(py)?;
@@ -74,6 +97,60 @@ impl crate::node::NodeBuilder {
             password,
             user_id: user_id.into(),
             session_id,
+            resume_timeout,
+            reconnect_policy: reconnect_policy.unwrap_or_default(),
+            http_config: http_config.unwrap_or_default(),
+            extra_headers: extra_headers.unwrap_or_default(),
+            connector: None,
         })
     }
 }
+
+#[pymethods]
+impl crate::node::ReconnectPolicy {
+    #[new]
+    #[pyo3(signature = (initial_delay=None, max_delay=None, multiplier=None, jitter=None, max_attempts=None))]
+    fn new(
+        initial_delay: Option<std::time::Duration>,
+        max_delay: Option<std::time::Duration>,
+        multiplier: Option<f64>,
+        jitter: Option<bool>,
+        max_attempts: Option<u32>,
+    ) -> Self {
+        let default = Self::default();
+
+        Self {
+            initial_delay: initial_delay.unwrap_or(default.initial_delay),
+            max_delay: max_delay.unwrap_or(default.max_delay),
+            multiplier: multiplier.unwrap_or(default.multiplier),
+            jitter: jitter.unwrap_or(default.jitter),
+            max_attempts: max_attempts.or(default.max_attempts),
+        }
+    }
+
+    #[pyo3(name = "delay")]
+    fn delay_py(&self, attempt: u32) -> std::time::Duration {
+        self.delay(attempt)
+    }
+}
+
+#[pymethods]
+impl crate::http::HttpConfig {
+    #[new]
+    #[pyo3(signature = (timeout=None, max_retries=None, retry_delay=None, max_concurrent_requests=None))]
+    fn new(
+        timeout: Option<std::time::Duration>,
+        max_retries: Option<u32>,
+        retry_delay: Option<std::time::Duration>,
+        max_concurrent_requests: Option<usize>,
+    ) -> Self {
+        let default = Self::default();
+
+        Self {
+            timeout: timeout.or(default.timeout),
+            max_retries: max_retries.unwrap_or(default.max_retries),
+            retry_delay: retry_delay.unwrap_or(default.retry_delay),
+            max_concurrent_requests: max_concurrent_requests.or(default.max_concurrent_requests),
+        }
+    }
+}
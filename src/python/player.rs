@@ -6,7 +6,7 @@ use crate::{
         player::{Filters, Player},
         track::TrackData,
     },
-    player_context::TrackInQueue,
+    player_context::{Crossfade, FadeCurve, LoopMode, PlayerSnapshot, QueuePolicy, TrackInQueue},
 };
 
 use parking_lot::RwLock;
@@ -17,6 +17,11 @@ pub fn player(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::player_context::PlayerContext>()?;
     m.add_class::<crate::player_context::TrackInQueue>()?;
     m.add_class::<crate::player_context::QueueRef>()?;
+    m.add_class::<crate::player_context::LoopMode>()?;
+    m.add_class::<crate::player_context::PlayerSnapshot>()?;
+    m.add_class::<crate::player_context::QueuePolicy>()?;
+    m.add_class::<crate::player_context::Crossfade>()?;
+    m.add_class::<crate::player_context::FadeCurve>()?;
 
     Ok(())
 }
@@ -129,6 +134,17 @@ impl crate::player_context::PlayerContext {
         })
     }
 
+    #[pyo3(name = "merge_filters")]
+    fn merge_filters_py<'a>(&self, py: Python<'a>, filters: Filters) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let player_inner = player.merge_filters(filters).await?;
+
+            Ok(Python::with_gil(|_py| player_inner))
+        })
+    }
+
     #[pyo3(name = "set_position_ms")]
     fn set_position_ms_py<'a>(&self, py: Python<'a>, position: u64) -> PyResult<Bound<'a, PyAny>> {
         let player = self.clone();
@@ -141,9 +157,14 @@ impl crate::player_context::PlayerContext {
     }
 
     #[pyo3(name = "queue")]
-    fn queue_py<'a>(&self, track: PyTrackInQueue) -> PyResult<()> {
-        self.queue(track)?;
-        Ok(())
+    fn queue_py<'a>(&self, py: Python<'a>, track: PyTrackInQueue) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            player.queue(track).await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "close")]
@@ -164,6 +185,119 @@ impl crate::player_context::PlayerContext {
         Ok(())
     }
 
+    #[pyo3(name = "set_loop_mode")]
+    fn set_loop_mode_py(&self, loop_mode: LoopMode) -> PyResult<()> {
+        self.set_loop_mode(loop_mode)?;
+        Ok(())
+    }
+
+    #[pyo3(name = "get_loop_mode")]
+    fn get_loop_mode_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let loop_mode = player.get_loop_mode().await?;
+
+            Ok(Python::with_gil(|_py| loop_mode))
+        })
+    }
+
+    #[pyo3(name = "previous")]
+    fn previous_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let track = player.previous().await?;
+
+            Ok(Python::with_gil(|_py| track))
+        })
+    }
+
+    #[pyo3(name = "fade_volume")]
+    #[pyo3(signature = (volume, duration, curve=FadeCurve::Linear))]
+    fn fade_volume_py<'a>(
+        &self,
+        py: Python<'a>,
+        volume: f64,
+        duration: Duration,
+        curve: FadeCurve,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let finished = player.fade_volume(volume, duration, curve).await?;
+
+            Ok(Python::with_gil(|_py| finished))
+        })
+    }
+
+    #[pyo3(name = "transition_filters")]
+    #[pyo3(signature = (filters, duration, curve=FadeCurve::Linear))]
+    fn transition_filters_py<'a>(
+        &self,
+        py: Python<'a>,
+        filters: Filters,
+        duration: Duration,
+        curve: FadeCurve,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let finished = player
+                .transition_filters_with_curve(filters, duration, curve)
+                .await?;
+
+            Ok(Python::with_gil(|_py| finished))
+        })
+    }
+
+    #[pyo3(name = "cancel_transition")]
+    fn cancel_transition_py(&self) -> PyResult<()> {
+        self.cancel_transition()?;
+        Ok(())
+    }
+
+    #[pyo3(name = "set_crossfade")]
+    #[pyo3(signature = (crossfade=None))]
+    fn set_crossfade_py(&self, crossfade: Option<Crossfade>) -> PyResult<()> {
+        self.set_crossfade(crossfade)?;
+        Ok(())
+    }
+
+    #[pyo3(name = "set_queue_policy")]
+    fn set_queue_policy_py(&self, queue_policy: QueuePolicy) -> PyResult<()> {
+        self.set_queue_policy(queue_policy)?;
+        Ok(())
+    }
+
+    #[pyo3(name = "get_queue_policy")]
+    fn get_queue_policy_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let queue_policy = player.get_queue_policy().await?;
+
+            Ok(Python::with_gil(|_py| queue_policy))
+        })
+    }
+
+    #[pyo3(name = "snapshot")]
+    fn snapshot_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let player = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let snapshot = player.snapshot().await?;
+
+            Ok(Python::with_gil(|_py| snapshot))
+        })
+    }
+
+    #[pyo3(name = "set_history_size")]
+    fn set_history_size_py(&self, history_size: usize) -> PyResult<()> {
+        self.set_history_size(history_size)?;
+        Ok(())
+    }
+
     #[pyo3(name = "update_player_data")]
     fn update_player_data_py<'a>(&self, player: Player) -> PyResult<()> {
         self.update_player_data(player)?;
@@ -215,6 +349,17 @@ impl crate::player_context::QueueRef {
         })
     }
 
+    #[pyo3(name = "get_history")]
+    fn get_history_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let history: Vec<_> = queue.get_history().await?.into();
+
+            Ok(Python::with_gil(|_py| history))
+        })
+    }
+
     #[pyo3(name = "get_count")]
     fn get_count_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
         let queue = self.clone();
@@ -227,43 +372,203 @@ impl crate::player_context::QueueRef {
     }
 
     #[pyo3(name = "push_to_back")]
-    fn push_to_back_py(&self, track: PyTrackInQueue) -> PyResult<()> {
-        Ok(self.push_to_back(TrackInQueue::from(track))?)
+    fn push_to_back_py<'a>(
+        &self,
+        py: Python<'a>,
+        track: PyTrackInQueue,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue.push_to_back(TrackInQueue::from(track)).await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "push_to_front")]
-    fn push_to_front_py(&self, track: PyTrackInQueue) -> PyResult<()> {
-        Ok(self.push_to_front(TrackInQueue::from(track))?)
+    fn push_to_front_py<'a>(
+        &self,
+        py: Python<'a>,
+        track: PyTrackInQueue,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue.push_to_front(TrackInQueue::from(track)).await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "insert")]
-    fn insert_py(&self, index: usize, track: PyTrackInQueue) -> PyResult<()> {
-        Ok(self.insert(index, TrackInQueue::from(track))?)
+    fn insert_py<'a>(
+        &self,
+        py: Python<'a>,
+        index: usize,
+        track: PyTrackInQueue,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue.insert(index, TrackInQueue::from(track)).await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "remove")]
-    fn remove_py(&self, index: usize) -> PyResult<()> {
-        Ok(self.remove(index)?)
+    fn remove_py<'a>(&self, py: Python<'a>, index: usize) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let track = queue.remove(index).await?;
+
+            Ok(Python::with_gil(|_py| track))
+        })
     }
 
     #[pyo3(name = "clear")]
-    fn clear_py(&self) -> PyResult<()> {
-        Ok(self.clear()?)
+    fn clear_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue.clear().await?;
+
+            Ok(())
+        })
+    }
+
+    #[pyo3(name = "shuffle")]
+    #[pyo3(signature = (seed=None))]
+    fn shuffle_py<'a>(&self, py: Python<'a>, seed: Option<u64>) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let tracks = queue.shuffle(seed).await?;
+
+            Ok(Python::with_gil(|_py| tracks))
+        })
+    }
+
+    #[pyo3(name = "move_track")]
+    fn move_track_py<'a>(
+        &self,
+        py: Python<'a>,
+        from: usize,
+        to: usize,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let track = queue.move_track(from, to).await?;
+
+            Ok(Python::with_gil(|_py| track))
+        })
+    }
+
+    #[pyo3(name = "swap_indices")]
+    fn swap_indices_py<'a>(
+        &self,
+        py: Python<'a>,
+        a: usize,
+        b: usize,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let tracks = queue.swap_indices(a, b).await?;
+
+            Ok(Python::with_gil(|_py| tracks))
+        })
+    }
+
+    #[pyo3(name = "remove_range")]
+    fn remove_range_py<'a>(
+        &self,
+        py: Python<'a>,
+        start: usize,
+        end: usize,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let tracks = queue.remove_range(start..end).await?;
+
+            Ok(Python::with_gil(|_py| tracks))
+        })
+    }
+
+    #[pyo3(name = "dedupe")]
+    fn dedupe_py<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let tracks = queue.dedupe().await?;
+
+            Ok(Python::with_gil(|_py| tracks))
+        })
+    }
+
+    #[pyo3(name = "skip")]
+    fn skip_py<'a>(&self, py: Python<'a>, amount: usize) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let tracks = queue.skip(amount).await?;
+
+            Ok(Python::with_gil(|_py| tracks))
+        })
     }
 
     #[pyo3(name = "replace")]
-    fn replace_py(&self, tracks: Vec<PyTrackInQueue>) -> PyResult<()> {
-        Ok(self.replace(tracks.into_iter().map(TrackInQueue::from).collect())?)
+    fn replace_py<'a>(
+        &self,
+        py: Python<'a>,
+        tracks: Vec<PyTrackInQueue>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue
+                .replace(tracks.into_iter().map(TrackInQueue::from).collect())
+                .await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "append")]
-    fn append_py(&self, tracks: Vec<PyTrackInQueue>) -> PyResult<()> {
-        Ok(self.append(tracks.into_iter().map(TrackInQueue::from).collect())?)
+    fn append_py<'a>(
+        &self,
+        py: Python<'a>,
+        tracks: Vec<PyTrackInQueue>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            queue
+                .append(tracks.into_iter().map(TrackInQueue::from).collect())
+                .await?;
+
+            Ok(())
+        })
     }
 
     #[pyo3(name = "swap")]
-    fn swap_py(&self, index: usize, track: PyTrackInQueue) -> PyResult<()> {
-        Ok(self.swap(index, TrackInQueue::from(track))?)
+    fn swap_py<'a>(
+        &self,
+        py: Python<'a>,
+        index: usize,
+        track: PyTrackInQueue,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let queue = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let track = queue.swap(index, TrackInQueue::from(track)).await?;
+
+            Ok(Python::with_gil(|_py| track))
+        })
     }
 
     // TODO: pyo3 0.21
@@ -324,3 +629,33 @@ impl From<PyTrackInQueue> for TrackInQueue {
         }
     }
 }
+
+#[pymethods]
+impl Crossfade {
+    #[new]
+    fn new_py(fade_out: Duration, fade_in: Duration) -> Crossfade {
+        Crossfade { fade_out, fade_in }
+    }
+}
+
+#[pymethods]
+impl QueuePolicy {
+    #[new]
+    fn new_py() -> QueuePolicy {
+        QueuePolicy::default()
+    }
+}
+
+#[pymethods]
+impl PlayerSnapshot {
+    /// Serialize the snapshot to JSON.
+    fn to_json(&self) -> PyResult<String> {
+        Ok(serde_json::to_string(self).map_err(crate::error::LavalinkError::from)?)
+    }
+
+    /// Deserialize a snapshot from JSON.
+    #[staticmethod]
+    fn from_json(json: &str) -> PyResult<Self> {
+        Ok(serde_json::from_str(json).map_err(crate::error::LavalinkError::from)?)
+    }
+}
@@ -0,0 +1,143 @@
+//! Managing the nodes of a client.
+
+use lavalink_rs::client::LavalinkClient;
+use lavalink_rs::error::LavalinkError;
+use lavalink_rs::model::client::NodeDistributionStrategy;
+use lavalink_rs::model::events::Events;
+use lavalink_rs::model::UserId;
+use lavalink_rs::node::NodeBuilder;
+
+fn node() -> NodeBuilder {
+    // Nothing listens on this port, so the nodes never connect.
+    NodeBuilder {
+        hostname: "127.0.0.1:1".to_string(),
+        is_ssl: false,
+        events: Events::default(),
+        password: "youshallnotpass".to_string(),
+        user_id: UserId(551759974905151548),
+        session_id: None,
+        resume_timeout: None,
+        reconnect_policy: Default::default(),
+        http_config: Default::default(),
+        extra_headers: Vec::new(),
+        connector: None,
+    }
+}
+
+#[tokio::test]
+async fn no_nodes() {
+    let client = LavalinkClient::new(
+        Events::default(),
+        Vec::new(),
+        NodeDistributionStrategy::new(),
+    )
+    .await;
+
+    assert!(matches!(client, Err(LavalinkError::NoNodesLeft)));
+}
+
+#[tokio::test]
+async fn remove_node() {
+    let client = LavalinkClient::new(
+        Events::default(),
+        vec![node(), node()],
+        NodeDistributionStrategy::new(),
+    )
+    .await
+    .unwrap();
+
+    assert!(matches!(
+        client.remove_node(5, false).await,
+        Err(LavalinkError::NodeNotFound)
+    ));
+
+    let removed = client.remove_node(0, false).await.unwrap();
+
+    assert_eq!(removed.id, 0);
+    assert!(client.get_node_by_id(0).is_none());
+    assert!(matches!(
+        client.remove_node(1, false).await,
+        Err(LavalinkError::NoNodesLeft)
+    ));
+}
+
+#[tokio::test]
+async fn concurrent_removals_keep_a_node() {
+    let client = LavalinkClient::new(
+        Events::default(),
+        vec![node(), node()],
+        NodeDistributionStrategy::new(),
+    )
+    .await
+    .unwrap();
+
+    let (first, second) = tokio::join!(client.remove_node(0, false), client.remove_node(1, false));
+
+    assert!(first.is_ok() != second.is_ok());
+    assert_eq!(client.nodes.load().len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_headers() {
+    let invalid_name = NodeBuilder {
+        extra_headers: vec![("X Proxy".to_string(), "token".to_string())],
+        ..node()
+    };
+
+    assert!(matches!(
+        LavalinkClient::new(
+            Events::default(),
+            vec![invalid_name],
+            NodeDistributionStrategy::new(),
+        )
+        .await,
+        Err(LavalinkError::InvalidHeaderName(_))
+    ));
+
+    let client = LavalinkClient::new(
+        Events::default(),
+        vec![node()],
+        NodeDistributionStrategy::new(),
+    )
+    .await
+    .unwrap();
+
+    let invalid_value = NodeBuilder {
+        extra_headers: vec![("X-Proxy".to_string(), "to\nken".to_string())],
+        ..node()
+    };
+
+    assert!(matches!(
+        client.add_node(invalid_value).await,
+        Err(LavalinkError::InvalidHeaderValue(_))
+    ));
+
+    let invalid_password = NodeBuilder {
+        password: "youshallnot\npass".to_string(),
+        ..node()
+    };
+
+    assert!(matches!(
+        client.add_node(invalid_password).await,
+        Err(LavalinkError::InvalidHeaderValue(_))
+    ));
+    assert_eq!(client.nodes.load().len(), 1);
+}
+
+#[tokio::test]
+async fn move_unbound_player() {
+    let client = LavalinkClient::new(
+        Events::default(),
+        vec![node()],
+        NodeDistributionStrategy::new(),
+    )
+    .await
+    .unwrap();
+    let target_node = client.get_node_by_index(0).unwrap();
+
+    assert!(matches!(
+        client.move_player(817327181659111454, target_node).await,
+        Err(LavalinkError::NodeNotFound)
+    ));
+    assert!(client.players.is_empty());
+}
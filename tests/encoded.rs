@@ -0,0 +1,185 @@
+//! Decoding and encoding of lavaplayer track blobs.
+
+use lavalink_rs::error::LavalinkError;
+use lavalink_rs::model::track::encoded::{self, EncodedTrack, LATEST_VERSION};
+use lavalink_rs::model::track::{TrackData, TrackInfo};
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Fixture {
+    version: u8,
+    encoded: String,
+    info: TrackInfo,
+    source_data: String,
+}
+
+fn fixtures() -> Vec<Fixture> {
+    serde_json::from_str(include_str!("payloads/encoded_tracks.json")).unwrap()
+}
+
+fn source_data(fixture: &Fixture) -> Vec<u8> {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD
+        .decode(&fixture.source_data)
+        .unwrap()
+}
+
+#[test]
+fn decode_fixtures() {
+    for fixture in fixtures() {
+        let track = EncodedTrack::decode(&fixture.encoded).unwrap();
+
+        assert_eq!(track.version, fixture.version);
+        assert_eq!(track.info, fixture.info);
+        assert_eq!(track.source_data, source_data(&fixture));
+    }
+}
+
+#[test]
+fn encode_fixtures() {
+    for fixture in fixtures() {
+        let track = EncodedTrack {
+            version: fixture.version,
+            source_data: source_data(&fixture),
+            info: fixture.info,
+        };
+
+        assert_eq!(track.encode().unwrap(), fixture.encoded);
+    }
+}
+
+#[test]
+fn decode_event_track() {
+    let raw: serde_json::Value =
+        serde_json::from_str(include_str!("payloads/track_start.json")).unwrap();
+    let track: TrackData = serde_json::from_value(raw["track"].clone()).unwrap();
+
+    // Lavalink builds the artwork url of YouTube tracks from the identifier, version 2 tracks
+    // don't contain it.
+    let info = TrackInfo {
+        artwork_url: None,
+        ..track.info
+    };
+
+    assert_eq!(encoded::decode(&track.encoded).unwrap(), info);
+}
+
+#[test]
+fn track_data_from_encoded() {
+    let fixture = &fixtures()[2];
+    let track = TrackData::from_encoded(fixture.encoded.clone()).unwrap();
+
+    assert_eq!(track.encoded, fixture.encoded);
+    assert_eq!(track.info, fixture.info);
+    assert_eq!(track.plugin_info, None);
+}
+
+#[test]
+fn encode_uses_latest_version() {
+    let fixture = &fixtures()[2];
+    let encoded = encoded::encode(&fixture.info).unwrap();
+
+    assert_eq!(encoded, fixture.encoded);
+    assert_eq!(
+        EncodedTrack::decode(&encoded).unwrap().version,
+        LATEST_VERSION
+    );
+}
+
+#[test]
+fn invalid_tracks() {
+    let fixture = &fixtures()[1];
+
+    for encoded in [
+        "",
+        "not base64!",
+        &fixture.encoded[..fixture.encoded.len() - 12],
+        // Version 4.
+        &fixture.encoded.replacen("QAAAjQI", "QAAAjQQ", 1),
+    ] {
+        assert!(matches!(
+            encoded::decode(encoded),
+            Err(LavalinkError::InvalidEncodedTrack(_))
+        ));
+    }
+}
+
+#[test]
+fn unsupported_fields() {
+    let fixture = &fixtures()[2];
+
+    for version in [1, 2] {
+        let track = EncodedTrack {
+            version,
+            info: fixture.info.clone(),
+            source_data: Vec::new(),
+        };
+
+        assert!(matches!(
+            track.encode(),
+            Err(LavalinkError::InvalidEncodedTrack(_))
+        ));
+    }
+
+    let info = TrackInfo {
+        length: u64::MAX,
+        ..fixture.info.clone()
+    };
+
+    assert!(matches!(
+        encoded::encode(&info),
+        Err(LavalinkError::InvalidEncodedTrack(_))
+    ));
+}
+
+fn random_string(rng: &mut fastrand::Rng) -> String {
+    const CHARS: &[char] = &[
+        'a', 'Z', '0', ' ', '\0', 'é', 'ß', '–', '中', '🎵', '\u{7FF}',
+    ];
+
+    (0..rng.usize(0..40))
+        .map(|_| match rng.u8(0..4) {
+            0 => rng.choice(CHARS).copied().unwrap(),
+            _ => rng.char(..),
+        })
+        .collect()
+}
+
+fn random_option(rng: &mut fastrand::Rng, present: bool) -> Option<String> {
+    (present && rng.bool()).then(|| random_string(rng))
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = fastrand::Rng::with_seed(0x6c61_7661);
+
+    for _ in 0..2000 {
+        let version = rng.u8(1..=LATEST_VERSION);
+        let is_stream = rng.bool();
+
+        let track = EncodedTrack {
+            version,
+            info: TrackInfo {
+                identifier: random_string(&mut rng),
+                is_seekable: !is_stream,
+                author: random_string(&mut rng),
+                length: rng.u64(..=i64::MAX as u64),
+                is_stream,
+                position: rng.u64(..=i64::MAX as u64),
+                title: random_string(&mut rng),
+                uri: random_option(&mut rng, version >= 2),
+                artwork_url: random_option(&mut rng, version >= 3),
+                isrc: random_option(&mut rng, version >= 3),
+                source_name: random_string(&mut rng),
+            },
+            source_data: (0..rng.usize(0..16)).map(|_| rng.u8(..)).collect(),
+        };
+
+        let encoded = track.encode().unwrap();
+        let decoded = EncodedTrack::decode(&encoded).unwrap();
+
+        assert_eq!(decoded, track);
+        assert_eq!(decoded.encode().unwrap(), encoded);
+    }
+}
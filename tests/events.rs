@@ -0,0 +1,173 @@
+//! Decoding of event payloads recorded from a Lavalink v4 server.
+
+use lavalink_rs::error::LavalinkError;
+use lavalink_rs::model::events::{LavalinkEvent, TrackEndReason};
+use lavalink_rs::model::GuildId;
+
+fn decode(payload: &str) -> Result<LavalinkEvent, LavalinkError> {
+    let raw: serde_json::Value = serde_json::from_str(payload).unwrap();
+    LavalinkEvent::decode(&raw)
+}
+
+#[test]
+fn ready() {
+    let Ok(LavalinkEvent::Ready(event)) = decode(include_str!("payloads/ready.json")) else {
+        panic!("expected a ready event");
+    };
+
+    assert_eq!(event.session_id, "la3kfsdf5eafe848");
+    assert!(!event.resumed);
+}
+
+#[test]
+fn player_update() {
+    let Ok(LavalinkEvent::PlayerUpdate(event)) =
+        decode(include_str!("payloads/player_update.json"))
+    else {
+        panic!("expected a player update event");
+    };
+
+    assert_eq!(event.guild_id, GuildId(817327181659111454));
+    assert_eq!(event.state.position, 60000);
+    assert!(event.state.connected);
+    assert_eq!(event.state.ping, Some(50));
+}
+
+#[test]
+fn stats() {
+    let Ok(LavalinkEvent::Stats(event)) = decode(include_str!("payloads/stats.json")) else {
+        panic!("expected a stats event");
+    };
+
+    assert_eq!(event.players, 1);
+    assert_eq!(event.cpu.cores, 4);
+    assert_eq!(event.frame_stats.unwrap().deficit, -3010);
+}
+
+#[test]
+fn track_start() {
+    let Ok(LavalinkEvent::TrackStart(event)) = decode(include_str!("payloads/track_start.json"))
+    else {
+        panic!("expected a track start event");
+    };
+
+    assert_eq!(event.guild_id, GuildId(817327181659111454));
+    assert_eq!(event.track.info.identifier, "dQw4w9WgXcQ");
+    assert_eq!(event.track.info.isrc, None);
+}
+
+#[test]
+fn track_end() {
+    let Ok(LavalinkEvent::TrackEnd(event)) = decode(include_str!("payloads/track_end.json")) else {
+        panic!("expected a track end event");
+    };
+
+    assert_eq!(event.reason, TrackEndReason::Finished);
+}
+
+#[test]
+fn track_exception() {
+    let Ok(LavalinkEvent::TrackException(event)) =
+        decode(include_str!("payloads/track_exception.json"))
+    else {
+        panic!("expected a track exception event");
+    };
+
+    assert_eq!(event.exception.severity, "common");
+    assert_eq!(event.exception.message, "This video is unavailable");
+}
+
+#[test]
+fn track_stuck() {
+    let Ok(LavalinkEvent::TrackStuck(event)) = decode(include_str!("payloads/track_stuck.json"))
+    else {
+        panic!("expected a track stuck event");
+    };
+
+    assert_eq!(event.threshold_ms, 123456789);
+}
+
+#[test]
+fn websocket_closed() {
+    let Ok(LavalinkEvent::WebSocketClosed(event)) =
+        decode(include_str!("payloads/websocket_closed.json"))
+    else {
+        panic!("expected a websocket closed event");
+    };
+
+    assert_eq!(event.code, 4006);
+    assert!(event.by_remote);
+}
+
+#[test]
+fn plugin_event_is_unknown() {
+    let payload = include_str!("payloads/plugin_event.json");
+
+    let Ok(LavalinkEvent::Unknown(raw)) = decode(payload) else {
+        panic!("expected an unknown event");
+    };
+
+    assert_eq!(
+        raw,
+        serde_json::from_str::<serde_json::Value>(payload).unwrap()
+    );
+}
+
+#[test]
+fn unknown_op() {
+    assert!(matches!(
+        decode(r#"{"op": "somethingNew"}"#),
+        Ok(LavalinkEvent::Unknown(_))
+    ));
+}
+
+#[test]
+fn missing_op() {
+    let Err(LavalinkError::SerdeErrorJson(why)) = decode(r#"{"sessionId": "abc"}"#) else {
+        panic!("expected a decode error");
+    };
+
+    assert!(why.to_string().contains("op"));
+}
+
+#[test]
+fn missing_event_type() {
+    assert!(matches!(
+        decode(r#"{"op": "event", "guildId": "1"}"#),
+        Err(LavalinkError::SerdeErrorJson(_))
+    ));
+}
+
+#[test]
+fn schema_mismatch() {
+    let payload = include_str!("payloads/ready.json").replace("false", "\"no\"");
+
+    assert!(matches!(
+        decode(&payload),
+        Err(LavalinkError::SerdeErrorJson(_))
+    ));
+}
+
+#[test]
+fn guild_id() {
+    let guild_id = Some(GuildId(817327181659111454));
+
+    assert_eq!(
+        decode(include_str!("payloads/track_start.json"))
+            .unwrap()
+            .guild_id(),
+        guild_id
+    );
+    assert_eq!(
+        decode(include_str!("payloads/plugin_event.json"))
+            .unwrap()
+            .guild_id(),
+        guild_id
+    );
+    assert_eq!(
+        decode(include_str!("payloads/stats.json"))
+            .unwrap()
+            .guild_id(),
+        None
+    );
+}
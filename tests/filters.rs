@@ -0,0 +1,219 @@
+//! Validation, merging and building of filters.
+
+use lavalink_rs::error::LavalinkError;
+use lavalink_rs::model::player::{
+    ChannelMix, Equalizer, FilterPreset, Filters, FiltersBuilder, Karaoke, LowPass, Timescale,
+    TremoloVibrato,
+};
+
+fn assert_invalid(filters: Filters) {
+    assert!(
+        matches!(filters.validate(), Err(LavalinkError::InvalidFilter(_))),
+        "{:?} should be invalid",
+        filters
+    );
+}
+
+#[test]
+fn empty_filters_are_valid() {
+    assert!(Filters::default().validate().is_ok());
+}
+
+#[test]
+fn presets_are_valid() {
+    for preset in FilterPreset::ALL {
+        assert!(preset.filters().validate().is_ok(), "{}", preset.name());
+    }
+}
+
+#[test]
+fn volume() {
+    for volume in [0.0, 1.0, 5.0] {
+        assert!(FiltersBuilder::new().volume(volume).build().is_ok());
+    }
+
+    for volume in [-0.1, 5.1, f64::NAN] {
+        assert_invalid(Filters {
+            volume: Some(volume),
+            ..Default::default()
+        });
+    }
+}
+
+#[test]
+fn equalizer() {
+    assert!(FiltersBuilder::new()
+        .equalizer_band(14, -0.25)
+        .equalizer_band(0, 1.0)
+        .build()
+        .is_ok());
+
+    assert_invalid(Filters {
+        equalizer: Some(vec![Equalizer {
+            band: 15,
+            gain: 0.0,
+        }]),
+        ..Default::default()
+    });
+    assert_invalid(Filters {
+        equalizer: Some(vec![Equalizer { band: 0, gain: 1.1 }]),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn karaoke() {
+    assert_invalid(Filters {
+        karaoke: Some(Karaoke {
+            level: Some(1.5),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+    assert_invalid(Filters {
+        karaoke: Some(Karaoke {
+            filter_band: Some(-220.0),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn timescale_must_be_positive() {
+    let timescale = |speed, pitch, rate| Filters {
+        timescale: Some(Timescale { speed, pitch, rate }),
+        ..Default::default()
+    };
+
+    assert!(timescale(Some(0.5), Some(2.0), Some(1.0))
+        .validate()
+        .is_ok());
+
+    assert_invalid(timescale(Some(0.0), None, None));
+    assert_invalid(timescale(None, Some(0.0), None));
+    assert_invalid(timescale(None, None, Some(0.0)));
+    assert_invalid(timescale(Some(-1.0), None, None));
+    assert_invalid(timescale(Some(f64::NAN), None, None));
+}
+
+#[test]
+fn tremolo_and_vibrato() {
+    let tremolo_vibrato = |frequency, depth| TremoloVibrato {
+        frequency: Some(frequency),
+        depth: Some(depth),
+    };
+
+    assert!(FiltersBuilder::new()
+        .tremolo(tremolo_vibrato(20.0, 1.0))
+        .vibrato(tremolo_vibrato(14.0, 0.5))
+        .build()
+        .is_ok());
+
+    for (frequency, depth) in [(0.0, 0.5), (2.0, 0.0), (2.0, 1.1)] {
+        assert_invalid(Filters {
+            tremolo: Some(tremolo_vibrato(frequency, depth)),
+            ..Default::default()
+        });
+    }
+
+    assert_invalid(Filters {
+        vibrato: Some(tremolo_vibrato(14.1, 0.5)),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn channel_mix_and_low_pass() {
+    assert_invalid(Filters {
+        channel_mix: Some(ChannelMix {
+            left_to_right: Some(1.5),
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    assert!(FiltersBuilder::new()
+        .low_pass(LowPass {
+            smoothing: Some(20.0),
+        })
+        .build()
+        .is_ok());
+    assert_invalid(Filters {
+        low_pass: Some(LowPass {
+            smoothing: Some(1.0),
+        }),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn builder_merges_filters() {
+    let filters = FiltersBuilder::new()
+        .timescale(Timescale {
+            speed: Some(1.2),
+            ..Default::default()
+        })
+        .timescale(Timescale {
+            pitch: Some(1.1),
+            ..Default::default()
+        })
+        .equalizer_band(0, 0.1)
+        .equalizer_band(1, 0.2)
+        .equalizer_band(0, 0.3)
+        .plugin_filter("echo", serde_json::json!({ "delay": 1.0 }))
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        filters.timescale,
+        Some(Timescale {
+            speed: Some(1.2),
+            pitch: Some(1.1),
+            rate: None,
+        })
+    );
+
+    let mut equalizer = filters.equalizer.unwrap();
+    equalizer.sort_by_key(|band| band.band);
+
+    assert_eq!(
+        equalizer,
+        vec![
+            Equalizer { band: 0, gain: 0.3 },
+            Equalizer { band: 1, gain: 0.2 },
+        ]
+    );
+    assert_eq!(
+        filters.plugin_filters,
+        Some(serde_json::json!({ "echo": { "delay": 1.0 } }))
+    );
+}
+
+#[test]
+fn builder_keeps_existing_filters() {
+    let filters = FiltersBuilder::from(FilterPreset::Nightcore.filters())
+        .volume(0.8)
+        .build()
+        .unwrap();
+
+    assert_eq!(filters.volume, Some(0.8));
+    assert_eq!(
+        filters.timescale,
+        FilterPreset::Nightcore.filters().timescale
+    );
+}
+
+#[test]
+fn builder_rejects_invalid_filters() {
+    assert!(matches!(
+        FiltersBuilder::new()
+            .preset(FilterPreset::Vaporwave)
+            .timescale(Timescale {
+                rate: Some(0.0),
+                ..Default::default()
+            })
+            .build(),
+        Err(LavalinkError::InvalidFilter(_))
+    ));
+}
@@ -0,0 +1,341 @@
+//! REST requests and their errors, against a local mock server.
+
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use lavalink_rs::error::LavalinkError;
+use lavalink_rs::http::{Connector, Http, HttpConfig};
+use lavalink_rs::model::http::UpdatePlayer;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const GUILD_ID: u64 = 817327181659111454;
+
+#[derive(Clone)]
+struct Response {
+    status: &'static str,
+    content_type: &'static str,
+    body: String,
+    delay: Duration,
+}
+
+fn response(status: &'static str, content_type: &'static str, body: &str) -> Response {
+    Response {
+        status,
+        content_type,
+        body: body.to_string(),
+        delay: Duration::ZERO,
+    }
+}
+
+#[derive(Clone, Default)]
+struct Counters {
+    requests: Arc<AtomicUsize>,
+    active: Arc<AtomicUsize>,
+    max_active: Arc<AtomicUsize>,
+}
+
+/// Serve the responses in order, one per connection, repeating the last one.
+async fn mock(responses: Vec<Response>) -> (SocketAddr, Counters) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let counters = Counters::default();
+
+    let task_counters = counters.clone();
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+
+            let index = task_counters.requests.fetch_add(1, Ordering::SeqCst);
+            let response = responses[index.min(responses.len() - 1)].clone();
+
+            tokio::spawn(handle(stream, response, task_counters.clone()));
+        }
+    });
+
+    (addr, counters)
+}
+
+async fn handle(mut stream: TcpStream, response: Response, counters: Counters) {
+    let active = counters.active.fetch_add(1, Ordering::SeqCst) + 1;
+    counters.max_active.fetch_max(active, Ordering::SeqCst);
+
+    let mut request = Vec::new();
+    let mut buf = [0; 1024];
+
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = stream.read(&mut buf).await.unwrap();
+        request.extend_from_slice(&buf[..n]);
+    }
+
+    tokio::time::sleep(response.delay).await;
+
+    let raw = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.content_type,
+        response.body.len(),
+        response.body
+    );
+
+    counters.active.fetch_sub(1, Ordering::SeqCst);
+
+    let _ = stream.write_all(raw.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// Serve a single response, and return an `Http` pointing at it.
+async fn serve(status: &'static str, content_type: &'static str, body: &str) -> Http {
+    let (addr, _) = mock(vec![response(status, content_type, body)]).await;
+
+    http(addr, config())
+}
+
+fn config() -> HttpConfig {
+    HttpConfig {
+        retry_delay: Duration::from_millis(10),
+        ..Default::default()
+    }
+}
+
+fn http(addr: SocketAddr, config: HttpConfig) -> Http {
+    let connector = Connector::new(hyper_util::client::legacy::connect::HttpConnector::new());
+
+    let request_client =
+        hyper_util::client::legacy::Client::builder(hyper_util::rt::TokioExecutor::new())
+            .build(connector);
+
+    Http::new(
+        &addr.to_string(),
+        false,
+        Default::default(),
+        Arc::new(request_client),
+        config,
+    )
+}
+
+fn response_error(status: u16, error: &str, message: &str) -> String {
+    serde_json::json!({
+        "timestamp": 1667857581613u64,
+        "status": status,
+        "error": error,
+        "trace": null,
+        "message": message,
+        "path": "/v4/sessions/xtaug914v9k5032f/players/817327181659111454",
+    })
+    .to_string()
+}
+
+#[tokio::test]
+async fn success() {
+    let http = serve("200 OK", "text/plain", "4.0.8").await;
+
+    assert_eq!(http.version().await.unwrap(), "4.0.8");
+}
+
+#[tokio::test]
+async fn unauthorized() {
+    let http = serve("401 Unauthorized", "text/plain", "").await;
+
+    assert!(matches!(
+        http.version().await,
+        Err(LavalinkError::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn not_found() {
+    let body = response_error(404, "Not Found", "Session not found");
+    let http = serve("404 Not Found", "application/json", &body).await;
+
+    let Err(LavalinkError::NotFound(why)) = http.get_player(GUILD_ID, "xtaug914v9k5032f").await
+    else {
+        panic!("expected a not found error");
+    };
+
+    assert_eq!(why.status, 404);
+    assert_eq!(why.message, "Session not found");
+}
+
+#[tokio::test]
+async fn bad_request() {
+    let body = response_error(400, "Bad Request", "Cannot set both encoded and identifier");
+    let http = serve("400 Bad Request", "application/json", &body).await;
+
+    let Err(LavalinkError::BadRequest(why)) = http
+        .update_player(
+            GUILD_ID,
+            "xtaug914v9k5032f",
+            &UpdatePlayer::default(),
+            false,
+        )
+        .await
+    else {
+        panic!("expected a bad request error");
+    };
+
+    assert_eq!(why.message, "Cannot set both encoded and identifier");
+}
+
+#[tokio::test]
+async fn other_response_error() {
+    let body = response_error(405, "Method Not Allowed", "Request method not supported");
+    let http = serve("405 Method Not Allowed", "application/json", &body).await;
+
+    assert!(matches!(
+        http.info().await,
+        Err(LavalinkError::ResponseError(why)) if why.status == 405
+    ));
+}
+
+#[tokio::test]
+async fn server_error() {
+    let body = "<html><body><h1>502 Bad Gateway</h1></body></html>";
+    let http = serve("502 Bad Gateway", "text/html", body).await;
+
+    let Err(LavalinkError::ServerError { status, body }) = http.load_tracks("dQw4w9WgXcQ").await
+    else {
+        panic!("expected a server error");
+    };
+
+    assert_eq!(status, 502);
+    assert!(body.contains("502 Bad Gateway"));
+}
+
+#[tokio::test]
+async fn unexpected_response() {
+    let body = "<html><body><h1>403 Forbidden</h1></body></html>";
+    let http = serve("403 Forbidden", "text/html", body).await;
+
+    assert!(matches!(
+        http.stats().await,
+        Err(LavalinkError::UnexpectedResponse { status: 403, .. })
+    ));
+}
+
+#[tokio::test]
+async fn invalid_success_body() {
+    let http = serve("200 OK", "text/html", "<html></html>").await;
+
+    assert!(matches!(
+        http.info().await,
+        Err(LavalinkError::SerdeErrorJson(_))
+    ));
+}
+
+#[tokio::test]
+async fn idempotent_requests_are_retried() {
+    let bad_gateway = response("502 Bad Gateway", "text/html", "<html></html>");
+    let (addr, counters) = mock(vec![
+        bad_gateway.clone(),
+        bad_gateway,
+        response("200 OK", "text/plain", "4.0.8"),
+    ])
+    .await;
+
+    assert_eq!(http(addr, config()).version().await.unwrap(), "4.0.8");
+    assert_eq!(counters.requests.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn retries_run_out() {
+    let (addr, counters) = mock(vec![response("503 Service Unavailable", "text/plain", "")]).await;
+    let config = HttpConfig {
+        max_retries: 1,
+        ..config()
+    };
+
+    assert!(matches!(
+        http(addr, config).version().await,
+        Err(LavalinkError::ServerError { status: 503, .. })
+    ));
+    assert_eq!(counters.requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn other_requests_are_not_retried() {
+    let (addr, counters) = mock(vec![
+        response("502 Bad Gateway", "text/html", "<html></html>"),
+        response("200 OK", "application/json", "[]"),
+    ])
+    .await;
+
+    assert!(matches!(
+        http(addr, config()).decode_tracks(&[]).await,
+        Err(LavalinkError::ServerError { status: 502, .. })
+    ));
+    assert_eq!(counters.requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let (addr, counters) = mock(vec![response("401 Unauthorized", "text/plain", "")]).await;
+
+    assert!(matches!(
+        http(addr, config()).version().await,
+        Err(LavalinkError::Unauthorized)
+    ));
+    assert_eq!(counters.requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn timeout() {
+    let (addr, _) = mock(vec![Response {
+        delay: Duration::from_secs(10),
+        ..response("200 OK", "text/plain", "4.0.8")
+    }])
+    .await;
+    let config = HttpConfig {
+        timeout: Some(Duration::from_millis(50)),
+        ..config()
+    };
+
+    assert!(matches!(
+        http(addr, config).version().await,
+        Err(LavalinkError::Timeout)
+    ));
+}
+
+#[tokio::test]
+async fn timeout_includes_retries() {
+    let (addr, counters) = mock(vec![Response {
+        delay: Duration::from_millis(30),
+        ..response("502 Bad Gateway", "text/html", "<html></html>")
+    }])
+    .await;
+    let config = HttpConfig {
+        timeout: Some(Duration::from_millis(100)),
+        max_retries: 10,
+        ..config()
+    };
+
+    assert!(matches!(
+        http(addr, config).version().await,
+        Err(LavalinkError::Timeout)
+    ));
+    assert!(counters.requests.load(Ordering::SeqCst) < 5);
+}
+
+#[tokio::test]
+async fn max_concurrent_requests() {
+    let (addr, counters) = mock(vec![Response {
+        delay: Duration::from_millis(20),
+        ..response("200 OK", "text/plain", "4.0.8")
+    }])
+    .await;
+    let config = HttpConfig {
+        max_concurrent_requests: Some(2),
+        ..config()
+    };
+    let http = http(addr, config);
+
+    let results = futures::future::join_all((0..6).map(|_| http.version())).await;
+
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(counters.requests.load(Ordering::SeqCst), 6);
+    assert_eq!(counters.max_active.load(Ordering::SeqCst), 2);
+}
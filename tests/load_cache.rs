@@ -0,0 +1,115 @@
+//! Caching of track loading results.
+
+use std::time::Duration;
+
+use lavalink_rs::client::{LoadCache, LoadCacheConfig, LoadCacheStats};
+use lavalink_rs::model::track::{Track, TrackLoadType};
+
+fn result(load_type: TrackLoadType) -> Track {
+    Track {
+        load_type,
+        data: None,
+    }
+}
+
+fn cache(capacity: usize) -> LoadCache {
+    LoadCache::new(LoadCacheConfig {
+        capacity,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn hits_and_misses() {
+    let cache = cache(10);
+
+    assert_eq!(cache.get("dQw4w9WgXcQ"), None);
+    cache.insert("dQw4w9WgXcQ", &result(TrackLoadType::Track));
+    assert_eq!(cache.get("dQw4w9WgXcQ"), Some(result(TrackLoadType::Track)));
+
+    assert_eq!(
+        cache.stats(),
+        LoadCacheStats {
+            hits: 1,
+            misses: 1,
+            len: 1,
+        }
+    );
+}
+
+#[test]
+fn errors_are_not_cached() {
+    let cache = cache(10);
+
+    cache.insert("error", &result(TrackLoadType::Error));
+    cache.insert("empty", &result(TrackLoadType::Empty));
+
+    assert_eq!(cache.get("error"), None);
+    assert_eq!(cache.get("empty"), None);
+    assert_eq!(cache.stats().len, 0);
+}
+
+#[test]
+fn least_recently_used_is_evicted() {
+    let cache = cache(2);
+
+    cache.insert("a", &result(TrackLoadType::Track));
+    cache.insert("b", &result(TrackLoadType::Playlist));
+    cache.get("a");
+    cache.insert("c", &result(TrackLoadType::Search));
+
+    assert!(cache.get("a").is_some());
+    assert!(cache.get("b").is_none());
+    assert!(cache.get("c").is_some());
+    assert_eq!(cache.stats().len, 2);
+}
+
+#[test]
+fn search_ttl() {
+    let cache = LoadCache::new(LoadCacheConfig {
+        search_ttl: Duration::from_millis(20),
+        ..Default::default()
+    });
+
+    cache.insert("ytsearch:never gonna", &result(TrackLoadType::Search));
+    cache.insert("dQw4w9WgXcQ", &result(TrackLoadType::Track));
+    assert!(cache.get("ytsearch:never gonna").is_some());
+
+    std::thread::sleep(Duration::from_millis(40));
+
+    assert!(cache.get("ytsearch:never gonna").is_none());
+    assert!(cache.get("dQw4w9WgXcQ").is_some());
+    assert_eq!(cache.stats().len, 1);
+}
+
+#[test]
+fn ttl_too_long_never_expires() {
+    let cache = LoadCache::new(LoadCacheConfig {
+        capacity: 1,
+        ttl: Duration::MAX,
+        search_ttl: Duration::MAX,
+    });
+
+    cache.insert("dQw4w9WgXcQ", &result(TrackLoadType::Track));
+    assert!(cache.get("dQw4w9WgXcQ").is_some());
+
+    // Making room for a new result only evicts the least recently used one.
+    cache.insert("ytsearch:never gonna", &result(TrackLoadType::Search));
+    assert!(cache.get("dQw4w9WgXcQ").is_none());
+    assert!(cache.get("ytsearch:never gonna").is_some());
+}
+
+#[test]
+fn invalidate_and_clear() {
+    let cache = cache(10);
+
+    cache.insert("a", &result(TrackLoadType::Track));
+    cache.insert("b", &result(TrackLoadType::Track));
+
+    cache.invalidate("a");
+    assert!(cache.get("a").is_none());
+    assert!(cache.get("b").is_some());
+
+    cache.clear();
+    assert_eq!(cache.stats().len, 0);
+}
@@ -0,0 +1,55 @@
+//! Reconnection delays of nodes.
+
+use std::time::Duration;
+
+use lavalink_rs::node::ReconnectPolicy;
+
+fn policy(multiplier: f64, jitter: bool) -> ReconnectPolicy {
+    ReconnectPolicy {
+        initial_delay: Duration::from_secs(2),
+        max_delay: Duration::from_secs(60),
+        multiplier,
+        jitter,
+        max_attempts: None,
+    }
+}
+
+#[test]
+fn fractional_multiplier() {
+    let policy = policy(1.5, false);
+
+    assert_eq!(policy.delay(0), Duration::from_secs(2));
+    assert_eq!(policy.delay(1), Duration::from_secs(3));
+    assert_eq!(policy.delay(2), Duration::from_millis(4500));
+}
+
+#[test]
+fn capped_at_max_delay() {
+    let policy = policy(2.0, false);
+
+    assert_eq!(policy.delay(5), Duration::from_secs(60));
+    assert_eq!(policy.delay(u32::MAX), Duration::from_secs(60));
+}
+
+#[test]
+fn jitter_stays_between_half_and_full_delay() {
+    let jittered = policy(2.0, true);
+    let full = policy(2.0, false);
+
+    for attempt in 0..10 {
+        let delay = jittered.delay(attempt);
+        let full = full.delay(attempt);
+
+        assert!(delay >= full / 2 && delay <= full);
+    }
+}
+
+#[test]
+fn huge_max_delay() {
+    let policy = ReconnectPolicy {
+        max_delay: Duration::MAX,
+        ..policy(10.0, true)
+    };
+
+    assert!(policy.delay(u32::MAX) >= Duration::MAX / 2);
+}
@@ -0,0 +1,176 @@
+//! The queue of a player context, against a local mock server.
+
+use std::net::SocketAddr;
+
+use futures::Stream;
+use lavalink_rs::client::LavalinkClient;
+use lavalink_rs::model::client::NodeDistributionStrategy;
+use lavalink_rs::model::events::Events;
+use lavalink_rs::model::player::{ConnectionInfo, Player, State};
+use lavalink_rs::model::track::{TrackData, TrackInfo};
+use lavalink_rs::model::{GuildId, UserId};
+use lavalink_rs::node::NodeBuilder;
+use lavalink_rs::player_context::{LoopMode, PlayerSnapshot, QueueEvent, TrackInQueue};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+const GUILD_ID: u64 = 817327181659111454;
+
+fn track(identifier: &str) -> TrackInQueue {
+    TrackData {
+        encoded: identifier.to_string(),
+        info: TrackInfo {
+            identifier: identifier.to_string(),
+            length: 60000,
+            is_seekable: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+    .into()
+}
+
+fn player(track: &TrackInQueue) -> Player {
+    Player {
+        guild_id: GuildId(GUILD_ID),
+        track: Some(track.track.clone()),
+        volume: 100,
+        paused: false,
+        state: State {
+            time: 1500467109,
+            position: 0,
+            connected: true,
+            ping: Some(50),
+        },
+        filters: None,
+        voice: connection_info(),
+    }
+}
+
+fn connection_info() -> ConnectionInfo {
+    ConnectionInfo {
+        endpoint: "sydney1798.discord.media".to_string(),
+        token: "d3e2b9a1f4c7".to_string(),
+        session_id: "0f8d3a2b1c9e".to_string(),
+        channel_id: None,
+    }
+}
+
+/// Answer every request with the player, as if the track kept playing.
+async fn mock(player: Player) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let body = serde_json::to_string(&player).unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let body = body.clone();
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match stream.read(&mut buf).await {
+                        Ok(0) | Err(_) => return,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+
+                let raw = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+
+                let _ = stream.write_all(raw.as_bytes()).await;
+                let _ = stream.shutdown().await;
+            });
+        }
+    });
+
+    addr
+}
+
+/// `QueueRef` is a stream too, so `StreamExt` can't be imported without hiding `QueueRef::skip()`.
+async fn next(events: &mut (impl Stream<Item = QueueEvent> + Unpin)) -> Option<QueueEvent> {
+    futures::StreamExt::next(events).await
+}
+
+async fn client(addr: SocketAddr) -> LavalinkClient {
+    let node = NodeBuilder {
+        hostname: addr.to_string(),
+        is_ssl: false,
+        events: Events::default(),
+        password: "youshallnotpass".to_string(),
+        user_id: UserId(551759974905151548),
+        session_id: None,
+        resume_timeout: None,
+        reconnect_policy: Default::default(),
+        http_config: Default::default(),
+        extra_headers: Vec::new(),
+        connector: None,
+    };
+
+    LavalinkClient::new(
+        Events::default(),
+        vec![node],
+        NodeDistributionStrategy::new(),
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn skip_with_queue_loop() {
+    let [a, b, c, d] = ["a", "b", "c", "d"].map(track);
+
+    let addr = mock(player(&a)).await;
+    let client = client(addr).await;
+
+    let snapshot = PlayerSnapshot {
+        player: player(&a),
+        current_track: Some(a.clone()),
+        queue: vec![b.clone(), c.clone(), d.clone()],
+        history: Vec::new(),
+        loop_mode: LoopMode::Queue,
+    };
+
+    let player_context = client
+        .restore_player_context(snapshot, connection_info())
+        .await
+        .unwrap();
+    let queue = player_context.get_queue();
+    let mut events = Box::pin(queue.subscribe());
+
+    assert_eq!(queue.skip(1).await.unwrap(), vec![b.clone()]);
+
+    assert_eq!(
+        next(&mut events).await,
+        Some(QueueEvent::Removed {
+            index: 0,
+            track: b.clone(),
+        })
+    );
+    assert_eq!(
+        next(&mut events).await,
+        Some(QueueEvent::Pushed {
+            index: 2,
+            tracks: vec![a.clone(), b.clone()],
+        })
+    );
+    assert_eq!(
+        next(&mut events).await,
+        Some(QueueEvent::Advanced {
+            track: Some(c.clone()),
+        })
+    );
+
+    let snapshot = player_context.snapshot().await.unwrap();
+
+    assert_eq!(snapshot.current_track, Some(c));
+    assert_eq!(snapshot.queue, vec![d, a.clone(), b]);
+    assert_eq!(snapshot.history, vec![a]);
+}
@@ -0,0 +1,154 @@
+//! Limits on the tracks that can be added to a queue.
+
+use std::time::Duration;
+
+use lavalink_rs::error::QueueRejection;
+use lavalink_rs::model::track::{TrackData, TrackInfo};
+use lavalink_rs::player_context::{QueuePolicy, TrackInQueue};
+
+fn track(identifier: &str, length: u64, requester: Option<u64>) -> TrackInQueue {
+    TrackData {
+        info: TrackInfo {
+            identifier: identifier.to_string(),
+            length,
+            is_seekable: true,
+            ..Default::default()
+        },
+        user_data: requester.map(|id| serde_json::json!({ "requester_id": id })),
+        ..Default::default()
+    }
+    .into()
+}
+
+fn stream(identifier: &str) -> TrackInQueue {
+    let mut track = track(identifier, i64::MAX as u64, None);
+    track.track.info.is_stream = true;
+    track.track.info.is_seekable = false;
+    track
+}
+
+#[test]
+fn no_limits() {
+    let queue = vec![track("a", 1000, Some(1)); 100];
+
+    assert_eq!(
+        QueuePolicy::default().check(&queue, &[stream("b"), track("c", 1000, Some(1))]),
+        Ok(())
+    );
+}
+
+#[test]
+fn max_length() {
+    let policy = QueuePolicy {
+        max_length: Some(3),
+        ..Default::default()
+    };
+    let queue = vec![track("a", 1000, None), track("b", 1000, None)];
+
+    assert_eq!(policy.check(&queue, &[track("c", 1000, None)]), Ok(()));
+    assert_eq!(
+        policy.check(&queue, &[track("c", 1000, None), track("d", 1000, None)]),
+        Err(QueueRejection::TooManyTracks { max_length: 3 })
+    );
+}
+
+#[test]
+fn max_duration() {
+    let max_duration = Duration::from_secs(10);
+    let policy = QueuePolicy {
+        max_duration: Some(max_duration),
+        ..Default::default()
+    };
+    let queue = vec![track("a", 4000, None), track("b", 4000, None)];
+
+    assert_eq!(policy.check(&queue, &[track("c", 2000, None)]), Ok(()));
+    assert_eq!(
+        policy.check(&queue, &[track("c", 2001, None)]),
+        Err(QueueRejection::TooLong { max_duration })
+    );
+}
+
+#[test]
+fn max_duration_skips_streams() {
+    let max_duration = Duration::from_secs(10);
+    let policy = QueuePolicy {
+        max_duration: Some(max_duration),
+        ..Default::default()
+    };
+    let queue = vec![stream("a"), track("b", 4000, None)];
+
+    assert_eq!(policy.check(&queue, &[stream("c")]), Ok(()));
+    assert_eq!(policy.check(&queue, &[track("d", 6000, None)]), Ok(()));
+    assert_eq!(
+        policy.check(&queue, &[stream("c"), track("d", 6001, None)]),
+        Err(QueueRejection::TooLong { max_duration })
+    );
+}
+
+#[test]
+fn reject_streams() {
+    let policy = QueuePolicy {
+        reject_streams: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        policy.check(&[], &[track("a", 1000, None), stream("b")]),
+        Err(QueueRejection::StreamNotAllowed {
+            identifier: "b".to_string()
+        })
+    );
+}
+
+#[test]
+fn requester_quota() {
+    let policy = QueuePolicy {
+        max_tracks_per_requester: Some(2),
+        ..Default::default()
+    };
+    let queue = vec![
+        track("a", 1000, Some(1)),
+        track("b", 1000, Some(2)),
+        track("c", 1000, Some(2)),
+        track("d", 1000, None),
+    ];
+
+    assert_eq!(
+        policy.check(&queue, &[track("e", 1000, Some(1)), track("f", 1000, None)]),
+        Ok(())
+    );
+    assert_eq!(
+        policy.check(&queue, &[track("e", 1000, Some(2))]),
+        Err(QueueRejection::RequesterQuotaExceeded {
+            requester: serde_json::json!(2),
+            max_tracks: 2,
+        })
+    );
+    assert_eq!(
+        policy.check(&[], &vec![track("e", 1000, Some(3)); 3]),
+        Err(QueueRejection::RequesterQuotaExceeded {
+            requester: serde_json::json!(3),
+            max_tracks: 2,
+        })
+    );
+}
+
+#[test]
+fn custom_requester_key() {
+    let policy = QueuePolicy {
+        max_tracks_per_requester: Some(1),
+        requester_key: "user".to_string(),
+        ..Default::default()
+    };
+    let mut other_key = track("a", 1000, None);
+    other_key.track.user_data = Some(serde_json::json!({ "user": "alice" }));
+
+    assert_eq!(
+        policy.check(&[track("b", 1000, Some(1))], &[track("c", 1000, Some(1))]),
+        Ok(())
+    );
+    assert!(matches!(
+        policy.check(&[other_key.clone()], &[other_key]),
+        Err(QueueRejection::RequesterQuotaExceeded { .. })
+    ));
+}